@@ -0,0 +1,340 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    Attribute, Data, DataEnum, DeriveInput, Fields, LitInt, LitStr, Variant,
+};
+
+/// Options of a variant from the `jder` attribute.
+#[derive(Default)]
+struct VariantOptions {
+    status: Option<u16>,
+    code: Option<String>,
+    field: Option<String>,
+    message: Option<LitStr>,
+}
+
+fn parse_variant_options(attrs: &[Attribute]) -> syn::Result<VariantOptions> {
+    let mut options: VariantOptions = VariantOptions::default();
+
+    for attr in attrs {
+        if !attr.path().is_ident("jder") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("status") {
+                let lit: LitInt = meta.value()?.parse()?;
+                let status: u16 = lit.base10_parse()?;
+
+                if !(100..=999).contains(&status) {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "status must be between 100 and 999",
+                    ));
+                }
+
+                options.status = Some(status);
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("code") {
+                let lit: LitStr = meta.value()?.parse()?;
+                options.code = Some(lit.value());
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("field") {
+                let lit: LitStr = meta.value()?.parse()?;
+                options.field = Some(lit.value());
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("message") {
+                options.message = Some(meta.value()?.parse()?);
+
+                return Ok(());
+            }
+
+            Err(meta.error(
+                "unsupported attribute, expected `status`, `code`, `field` or `message`",
+            ))
+        })?;
+    }
+
+    Ok(options)
+}
+
+/// Convert a variant name into `snake_case`,
+/// treating a run of capitals as one word,
+/// e.g. `HTTPError` into `http_error`.
+fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+
+    let mut result: String = String::with_capacity(name.len() + 4);
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev: char = chars[i - 1];
+            let next: Option<&char> = chars.get(i + 1);
+
+            // start of a word, or the last capital of a run before a word
+            let is_boundary: bool = prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase()
+                    && next.is_some_and(|next| next.is_lowercase()));
+
+            if is_boundary {
+                result.push('_');
+            }
+        }
+
+        result.extend(c.to_lowercase());
+    }
+
+    result
+}
+
+/// Rewrite a message template, replacing `{0}` with `{__0}`,
+/// and collect the names of referenced arguments.
+fn parse_message_template(lit: &LitStr) -> syn::Result<(String, Vec<String>)> {
+    let template: String = lit.value();
+
+    let mut result: String = String::with_capacity(template.len());
+    let mut names: Vec<String> = Vec::new();
+
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            | '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                result.push_str("{{");
+            },
+            | '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                result.push_str("}}");
+            },
+            | '{' => {
+                let mut inner: String = String::new();
+
+                loop {
+                    match chars.next() {
+                        | Some('}') => break,
+                        | Some(c) => inner.push(c),
+                        | None => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "unclosed `{` in message",
+                            ));
+                        },
+                    }
+                }
+
+                let (name, spec) = match inner.split_once(':') {
+                    | Some((name, spec)) => (name.trim(), Some(spec)),
+                    | None => (inner.trim(), None),
+                };
+
+                if name.is_empty() {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "positional `{}` is not supported in message, use `{name}` or `{0}` instead",
+                    ));
+                }
+
+                let name: String =
+                    match name.chars().all(|c| c.is_ascii_digit()) {
+                        | true => format!("__{}", name),
+                        | false => name.to_string(),
+                    };
+
+                result.push('{');
+                result.push_str(&name);
+
+                if let Some(spec) = spec {
+                    result.push(':');
+                    result.push_str(spec);
+                }
+
+                result.push('}');
+
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            },
+            | c => result.push(c),
+        }
+    }
+
+    Ok((result, names))
+}
+
+/// Create the match arm for the message of a variant.
+fn expand_message_arm(
+    variant: &Variant,
+    lit: &LitStr,
+) -> syn::Result<TokenStream> {
+    let ident: &Ident = &variant.ident;
+
+    let (template, names) = parse_message_template(lit)?;
+
+    let bindings: Vec<Ident> = match &variant.fields {
+        | Fields::Named(fields) => fields
+            .named
+            .iter()
+            .filter_map(|field| field.ident.clone())
+            .collect(),
+        | Fields::Unnamed(fields) => (0..fields.unnamed.len())
+            .map(|i| format_ident!("__{}", i))
+            .collect(),
+        | Fields::Unit => Vec::new(),
+    };
+
+    let mut args: Vec<Ident> = Vec::with_capacity(names.len());
+
+    for name in &names {
+        match bindings.iter().find(|binding| *binding == name) {
+            | Some(binding) => args.push(binding.clone()),
+            | None => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    format!(
+                        "`{}` is not a field of `{}`",
+                        name.trim_start_matches("__"),
+                        ident
+                    ),
+                ));
+            },
+        }
+    }
+
+    let pattern: TokenStream = match &variant.fields {
+        | Fields::Named(_) => quote!(Self::#ident { #(#bindings),* }),
+        | Fields::Unnamed(_) => quote!(Self::#ident(#(#bindings),*)),
+        | Fields::Unit => quote!(Self::#ident),
+    };
+
+    let template: LitStr = LitStr::new(&template, lit.span());
+
+    Ok(quote! {
+        #[allow(unused_variables)]
+        #pattern => ::core::option::Option::Some(
+            ::std::format!(#template, #(#args = #args),*)
+        ),
+    })
+}
+
+fn expand_enum(
+    input: &DeriveInput,
+    data: &DataEnum,
+) -> syn::Result<TokenStream> {
+    let ident: &Ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    let mut code_arms: Vec<TokenStream> = Vec::new();
+    let mut status_arms: Vec<TokenStream> = Vec::new();
+    let mut field_arms: Vec<TokenStream> = Vec::new();
+    let mut message_arms: Vec<TokenStream> = Vec::new();
+
+    for variant in &data.variants {
+        let options: VariantOptions = parse_variant_options(&variant.attrs)?;
+
+        let variant_ident: &Ident = &variant.ident;
+
+        let pattern: TokenStream = match &variant.fields {
+            | Fields::Named(_) => quote!(Self::#variant_ident { .. }),
+            | Fields::Unnamed(_) => quote!(Self::#variant_ident(..)),
+            | Fields::Unit => quote!(Self::#variant_ident),
+        };
+
+        let code: String = match options.code {
+            | Some(code) => code,
+            | None => to_snake_case(&variant_ident.to_string()),
+        };
+
+        code_arms.push(quote!(#pattern => #code,));
+
+        let status: u16 = options.status.unwrap_or(400);
+
+        status_arms.push(quote!(#pattern => #status,));
+
+        field_arms.push(match options.field {
+            | Some(field) => {
+                quote!(#pattern => ::core::option::Option::Some(#field),)
+            },
+            | None => quote!(#pattern => ::core::option::Option::None,),
+        });
+
+        message_arms.push(match &options.message {
+            | Some(lit) => expand_message_arm(variant, lit)?,
+            | None => quote!(#pattern => ::core::option::Option::None,),
+        });
+    }
+
+    // empty enums can only be matched by value
+    let message_body: TokenStream = match data.variants.is_empty() {
+        | true => quote!(match *self {}),
+        | false => quote!(match self { #(#message_arms)* }),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::jder_axum::response::json::ErrorCode
+            for #ident #ty_generics #where_clause
+        {
+            fn code(&self) -> &str {
+                match *self {
+                    #(#code_arms)*
+                }
+            }
+
+            fn status(&self) -> ::jder_axum::__private::StatusCode {
+                let status: u16 = match *self {
+                    #(#status_arms)*
+                };
+
+                match ::jder_axum::__private::StatusCode::from_u16(status) {
+                    | ::core::result::Result::Ok(status) => status,
+                    | ::core::result::Result::Err(_) => {
+                        ::jder_axum::__private::StatusCode::INTERNAL_SERVER_ERROR
+                    },
+                }
+            }
+
+            fn field(&self) -> ::core::option::Option<&str> {
+                match *self {
+                    #(#field_arms)*
+                }
+            }
+
+            fn message(
+                &self,
+            ) -> ::core::option::Option<::std::string::String> {
+                #message_body
+            }
+        }
+
+        impl #impl_generics ::jder_axum::__private::IntoResponse
+            for #ident #ty_generics #where_clause
+        {
+            fn into_response(self) -> ::jder_axum::response::Response {
+                ::jder_axum::__private::IntoResponse::into_response(
+                    ::jder_axum::error::JderError::new(self),
+                )
+            }
+        }
+    })
+}
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    match &input.data {
+        | Data::Enum(data) => expand_enum(&input, data),
+        | _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "`JderError` can only be derived for enums",
+        )),
+    }
+}
@@ -0,0 +1,51 @@
+//! # JDER axum derive
+//!
+//! Derive macros for [jder_axum](https://docs.rs/jder_axum).
+//!
+//! This package is re-exported by `jder_axum` with the `derive` feature.
+
+mod error;
+
+use proc_macro::TokenStream;
+use syn::{DeriveInput, parse_macro_input};
+
+/// Derive `ErrorCode` and `IntoResponse` for an error enum,
+/// so each variant is sent as a JSON failure response.
+///
+/// Each variant can be configured with the `jder` attribute:
+///
+/// - `status`: status code of the response, `400` by default.
+/// - `code`: error code, the variant name in `snake_case` by default,
+///   e.g. `http_error` for `HTTPError`.
+/// - `field`: field of the error.
+/// - `message`: message of the error,
+///   fields of the variant can be used with `{name}` or `{0}`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::error::JderError;
+///
+/// #[derive(JderError)]
+/// enum UserError {
+///     #[jder(status = 404, code = "user_not_found", message = "User {id} not found")]
+///     NotFound { id: u64 },
+///     #[jder(status = 422, field = "email", message = "Invalid email: {0}")]
+///     InvalidEmail(String),
+///     #[jder(status = 500)]
+///     Database,
+/// }
+///
+/// async fn route() -> Result<(), UserError> {
+///     Err(UserError::NotFound { id: 1 })
+/// }
+/// ```
+#[proc_macro_derive(JderError, attributes(jder))]
+pub fn derive_jder_error(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+
+    match error::expand(input) {
+        | Ok(tokens) => tokens.into(),
+        | Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,124 @@
+use axum::{
+    body::{Bytes, to_bytes},
+    http::StatusCode,
+};
+use jder_axum::{
+    error::JderError,
+    response::{
+        Response,
+        json::{CreateJsonResponse, ErrorCode},
+    },
+};
+
+#[derive(JderError)]
+enum UserError {
+    #[jder(
+        status = 404,
+        code = "user_not_found",
+        message = "User {id} not found"
+    )]
+    NotFound {
+        id: u64,
+        name: String,
+    },
+    #[jder(status = 422, field = "email", message = "Invalid email: {0:?}")]
+    InvalidEmail(String),
+    #[jder(message = "Rate limited, {{retry}} later")]
+    RateLimited,
+    #[jder(status = 500)]
+    HTTPError,
+    IOError,
+    Http2Error,
+    TooManyRequests,
+}
+
+/// Check that two responses are the same byte for byte.
+async fn assert_same_response(
+    derived: Response,
+    manual: Response,
+) {
+    assert_eq!(derived.status(), manual.status());
+    assert_eq!(derived.headers(), manual.headers());
+
+    let derived: Bytes =
+        to_bytes(derived.into_body(), usize::MAX).await.unwrap();
+    let manual: Bytes = to_bytes(manual.into_body(), usize::MAX).await.unwrap();
+
+    assert_eq!(derived, manual);
+}
+
+#[tokio::test]
+async fn test_named_fields() {
+    let derived: Response =
+        axum::response::IntoResponse::into_response(UserError::NotFound {
+            id: 1,
+            name: "Name".to_string(),
+        });
+
+    let manual: Response = CreateJsonResponse::failure()
+        .status(StatusCode::NOT_FOUND)
+        .error_code("user_not_found")
+        .error_message("User 1 not found")
+        .send();
+
+    assert_same_response(derived, manual).await;
+}
+
+#[tokio::test]
+async fn test_unnamed_fields() {
+    let derived: Response = axum::response::IntoResponse::into_response(
+        UserError::InvalidEmail("email".to_string()),
+    );
+
+    let manual: Response = CreateJsonResponse::failure()
+        .status(StatusCode::UNPROCESSABLE_ENTITY)
+        .error_code("invalid_email")
+        .error_field("email")
+        .error_message("Invalid email: \"email\"")
+        .send();
+
+    assert_same_response(derived, manual).await;
+}
+
+#[tokio::test]
+async fn test_unit() {
+    let derived: Response =
+        axum::response::IntoResponse::into_response(UserError::RateLimited);
+
+    let manual: Response = CreateJsonResponse::failure()
+        .status(StatusCode::BAD_REQUEST)
+        .error_code("rate_limited")
+        .error_message("Rate limited, {retry} later")
+        .send();
+
+    assert_same_response(derived, manual).await;
+}
+
+#[tokio::test]
+async fn test_jder_error() {
+    let derived: Response = axum::response::IntoResponse::into_response(
+        JderError::from(UserError::HTTPError),
+    );
+
+    let manual: Response = CreateJsonResponse::failure()
+        .status(StatusCode::INTERNAL_SERVER_ERROR)
+        .error_code("http_error")
+        .send();
+
+    assert_same_response(derived, manual).await;
+}
+
+#[test]
+fn test_snake_case() {
+    assert_eq!(UserError::HTTPError.code(), "http_error");
+    assert_eq!(UserError::IOError.code(), "io_error");
+    assert_eq!(UserError::Http2Error.code(), "http2_error");
+    assert_eq!(UserError::TooManyRequests.code(), "too_many_requests");
+}
+
+#[test]
+fn test_defaults() {
+    assert_eq!(UserError::IOError.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(UserError::IOError.field(), None);
+    assert_eq!(UserError::IOError.message(), None);
+}
@@ -0,0 +1,6 @@
+#[test]
+fn test_ui() {
+    let cases: trybuild::TestCases = trybuild::TestCases::new();
+
+    cases.compile_fail("tests/ui/*.rs");
+}
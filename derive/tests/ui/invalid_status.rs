@@ -0,0 +1,9 @@
+use jder_axum::error::JderError;
+
+#[derive(JderError)]
+enum UserError {
+    #[jder(status = 1000)]
+    NotFound,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use jder_axum::error::JderError;
+
+#[derive(JderError)]
+struct UserError {
+    id: u64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use jder_axum::error::JderError;
+
+#[derive(JderError)]
+enum UserError {
+    #[jder(message = "Invalid email: {}")]
+    InvalidEmail(String),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use jder_axum::error::JderError;
+
+#[derive(JderError)]
+enum UserError {
+    #[jder(message = "User {id not found")]
+    NotFound { id: u64 },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use jder_axum::error::JderError;
+
+#[derive(JderError)]
+enum UserError {
+    #[jder(reason = "missing")]
+    NotFound,
+}
+
+fn main() {}
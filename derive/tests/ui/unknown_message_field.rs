@@ -0,0 +1,9 @@
+use jder_axum::error::JderError;
+
+#[derive(JderError)]
+enum UserError {
+    #[jder(message = "User {name} not found")]
+    NotFound { id: u64 },
+}
+
+fn main() {}
@@ -0,0 +1,168 @@
+//! Benchmark of JSON response serialization.
+//!
+//! Counts heap allocations and measures time per response,
+//! comparing `CreateJsonResponse` with serializing through a `String`.
+//!
+//! Run with `cargo bench -p jder_axum --bench json_response`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+use axum::body::{Body, HttpBody};
+use http::{StatusCode, header};
+use jder_axum::response::{
+    Response,
+    json::{CreateJsonResponse, JsonResponse},
+};
+use serde::Serialize;
+
+/// Global allocator that counts allocations and allocated bytes.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(
+        &self,
+        layout: Layout,
+    ) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+    ) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(new_size, Ordering::Relaxed);
+
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const ITERATIONS: usize = 100_000;
+
+#[derive(Clone, Serialize)]
+struct User {
+    id: u64,
+    username: String,
+    email: String,
+    roles: Vec<String>,
+}
+
+fn create_users(count: usize) -> Vec<User> {
+    (0..count)
+        .map(|i| User {
+            id: i as u64,
+            username: format!("user_{}", i),
+            email: format!("user_{}@example.com", i),
+            roles: vec!["admin".to_string(), "editor".to_string()],
+        })
+        .collect()
+}
+
+/// Serialize through a `String` and build the fallback eagerly,
+/// as responses were created before serializing into bytes.
+fn send_with_string<D: Serialize>(data: D) -> Response {
+    let server_error: Response = Response::builder()
+        .status(StatusCode::INTERNAL_SERVER_ERROR)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from("{\"success\":false}".to_string()))
+        .unwrap();
+
+    let res: JsonResponse<D> = JsonResponse {
+        success: true,
+        data: Some(data),
+        error: None,
+        errors: Vec::new(),
+        pagination: None,
+    };
+
+    let body: String = match serde_json::to_string(&res) {
+        | Ok(body) => body,
+        | Err(_) => return server_error,
+    };
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body))
+        .unwrap()
+}
+
+/// Serialize with `CreateJsonResponse`,
+/// reading the size of the body as deferred bodies are serialized then.
+fn send_with_bytes<D: Serialize + Send + 'static>(data: D) -> Response {
+    let res: Response = CreateJsonResponse::success::<D>().data(data).send();
+
+    black_box(HttpBody::size_hint(res.body()));
+
+    res
+}
+
+/// Run a case and print allocations and time per response.
+fn bench<F: Fn() -> Response>(
+    name: &str,
+    f: F,
+) {
+    // warm up
+    for _ in 0..1_000 {
+        black_box(f());
+    }
+
+    let allocations: usize = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated: usize = ALLOCATED.load(Ordering::Relaxed);
+    let start: Instant = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+
+    let elapsed: Duration = start.elapsed();
+    let allocations: usize = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    let allocated: usize = ALLOCATED.load(Ordering::Relaxed) - allocated;
+
+    println!(
+        "{:<24} {:>8.2} allocs/op {:>10.1} bytes/op {:>10.1} ns/op",
+        name,
+        allocations as f64 / ITERATIONS as f64,
+        allocated as f64 / ITERATIONS as f64,
+        elapsed.as_nanos() as f64 / ITERATIONS as f64,
+    );
+}
+
+fn main() {
+    bench("dataless/string", || send_with_string(()));
+    bench("dataless/bytes", || send_with_bytes(()));
+
+    let user: User = create_users(1).remove(0);
+
+    bench("user/string", || send_with_string(user.clone()));
+    bench("user/bytes", || send_with_bytes(user.clone()));
+
+    let users: Vec<User> = create_users(100);
+
+    bench("users/string", || send_with_string(users.clone()));
+    bench("users/bytes", || send_with_bytes(users.clone()));
+}
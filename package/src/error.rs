@@ -0,0 +1,298 @@
+use axum_core::response::IntoResponse;
+use http::StatusCode;
+
+use crate::response::{
+    Response,
+    json::{
+        CreateJsonResponse, ErrorCode, JsonResponseError, JsonResponseErrorCode,
+    },
+};
+
+/// Derive macro for mapping error enums to JSON failure responses,
+/// available with `derive` feature.
+#[cfg(feature = "derive")]
+pub use jder_axum_derive::JderError;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Message of server errors converted from other errors,
+/// as their details should not be sent to clients.
+const SERVER_ERROR_MESSAGE: &str = "Internal server error.";
+
+/// Error for routes that can be returned as a JSON failure response.
+///
+/// The status code, error code, error field and error message
+/// are used to create the response,
+/// while the source error is only kept for logging and not serialized.
+///
+/// Errors converted with `?` from I/O errors,
+/// serde_json I/O errors, `anyhow` and `eyre` errors
+/// are sent with status code `500`, error code `server`
+/// and a generic message, so their details are only kept in the source.
+///
+/// ## Examples
+///
+/// An example of using `JderError` with `?` in a route:
+///
+/// ```no_run
+/// use jder_axum::{
+///     error::JderError,
+///     extract::Json,
+/// };
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// async fn route() -> Result<Json<User>, JderError> {
+///     let user: User = serde_json::from_str("{\"name\":\"Name\"}")?;
+///
+///     Ok(Json(user))
+/// }
+/// ```
+///
+/// An example of creating a `JderError` from an error code:
+///
+/// ```no_run
+/// use jder_axum::{
+///     error::JderError,
+///     response::json::JsonResponseErrorCode,
+/// };
+///
+/// async fn route() -> Result<(), JderError> {
+///     Err(JderError::new(JsonResponseErrorCode::NotFound)
+///         .with_field("id")
+///         .with_message("User not found"))
+/// }
+/// ```
+#[derive(Debug)]
+pub struct JderError {
+    /// Status code of the response.
+    pub status: StatusCode,
+    /// Error code.
+    pub code: String,
+    /// Field of the error.
+    pub field: Option<String>,
+    /// Message of the error.
+    pub message: Option<String>,
+    source: Option<BoxError>,
+}
+
+impl JderError {
+    /// Create a new error with the status code, error code,
+    /// error field and error message from an [`ErrorCode`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     response::json::JsonResponseErrorCode,
+    /// };
+    ///
+    /// let error: JderError = JderError::new(JsonResponseErrorCode::Conflict);
+    /// ```
+    pub fn new<C: ErrorCode>(code: C) -> Self {
+        Self {
+            status: code.status(),
+            code: code.code().to_string(),
+            field: code.field().map(|field| field.to_string()),
+            message: code.message(),
+            source: None,
+        }
+    }
+
+    /// Set the status code of the error.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     response::json::JsonResponseErrorCode,
+    /// };
+    ///
+    /// let error: JderError = JderError::new(JsonResponseErrorCode::Parse)
+    ///     .with_status(StatusCode::UNPROCESSABLE_ENTITY);
+    /// ```
+    pub fn with_status<S: Into<StatusCode>>(
+        mut self,
+        status: S,
+    ) -> Self {
+        self.status = status.into();
+
+        self
+    }
+
+    /// Set the field of the error.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     response::json::JsonResponseErrorCode,
+    /// };
+    ///
+    /// let error: JderError = JderError::new(JsonResponseErrorCode::Parse)
+    ///     .with_field("title");
+    /// ```
+    pub fn with_field<S: Into<String>>(
+        mut self,
+        field: S,
+    ) -> Self {
+        self.field = Some(field.into());
+
+        self
+    }
+
+    /// Set the message of the error.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     response::json::JsonResponseErrorCode,
+    /// };
+    ///
+    /// let error: JderError = JderError::new(JsonResponseErrorCode::Parse)
+    ///     .with_message("Invalid title");
+    /// ```
+    pub fn with_message<S: Into<String>>(
+        mut self,
+        message: S,
+    ) -> Self {
+        self.message = Some(message.into());
+
+        self
+    }
+
+    /// Set the source of the error,
+    /// which will not be included in the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     response::json::JsonResponseErrorCode,
+    /// };
+    ///
+    /// let source: std::io::Error = std::io::Error::other("disk failure");
+    ///
+    /// let error: JderError = JderError::new(JsonResponseErrorCode::Server)
+    ///     .with_source(source);
+    /// ```
+    pub fn with_source<E: Into<BoxError>>(
+        mut self,
+        source: E,
+    ) -> Self {
+        self.source = Some(source.into());
+
+        self
+    }
+
+    /// Create an error from a [`JsonResponseError`] with a status code.
+    pub(crate) fn from_json_response_error(
+        status: StatusCode,
+        error: JsonResponseError,
+    ) -> Self {
+        Self {
+            status,
+            code: error.code,
+            field: error.field,
+            message: error.message,
+            source: None,
+        }
+    }
+
+    /// Get the error as a [`JsonResponseError`].
+    pub fn to_json_response_error(&self) -> JsonResponseError {
+        JsonResponseError {
+            code: self.code.clone(),
+            field: self.field.clone(),
+            message: self.message.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for JderError {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match &self.message {
+            | Some(message) => write!(f, "{}", message),
+            | None => write!(f, "{}", self.code),
+        }
+    }
+}
+
+impl std::error::Error for JderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.source {
+            | Some(source) => Some(source.as_ref()),
+            | None => None,
+        }
+    }
+}
+
+impl IntoResponse for JderError {
+    fn into_response(self) -> Response {
+        CreateJsonResponse::failure()
+            .status(self.status)
+            .error(self.to_json_response_error())
+            .send()
+    }
+}
+
+impl<C: ErrorCode> From<C> for JderError {
+    fn from(code: C) -> Self {
+        Self::new(code)
+    }
+}
+
+impl From<serde_json::Error> for JderError {
+    fn from(error: serde_json::Error) -> Self {
+        match error.classify() {
+            | serde_json::error::Category::Io => {
+                Self::new(JsonResponseErrorCode::Server)
+                    .with_message(SERVER_ERROR_MESSAGE)
+                    .with_source(error)
+            },
+            | _ => Self::new(JsonResponseErrorCode::Parse)
+                .with_message(error.to_string())
+                .with_source(error),
+        }
+    }
+}
+
+impl From<std::io::Error> for JderError {
+    fn from(error: std::io::Error) -> Self {
+        Self::new(JsonResponseErrorCode::Server)
+            .with_message(SERVER_ERROR_MESSAGE)
+            .with_source(error)
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl From<anyhow::Error> for JderError {
+    fn from(error: anyhow::Error) -> Self {
+        Self::new(JsonResponseErrorCode::Server)
+            .with_message(SERVER_ERROR_MESSAGE)
+            .with_source(error)
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl From<eyre::Report> for JderError {
+    fn from(error: eyre::Report) -> Self {
+        Self::new(JsonResponseErrorCode::Server)
+            .with_message(SERVER_ERROR_MESSAGE)
+            .with_source(error)
+    }
+}
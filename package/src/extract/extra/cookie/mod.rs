@@ -0,0 +1,214 @@
+/// Signed cookie jar module,
+/// available with `extra_cookie_signed` feature.
+#[cfg(feature = "extra_cookie_signed")]
+pub mod signed;
+
+/// Private cookie jar module,
+/// available with `extra_cookie_private` feature.
+#[cfg(feature = "extra_cookie_private")]
+pub mod private;
+
+#[cfg(feature = "extra_cookie_signed")]
+pub use crate::extract::extra::cookie::signed::SignedCookieJar;
+
+#[cfg(feature = "extra_cookie_private")]
+pub use crate::extract::extra::cookie::private::PrivateCookieJar;
+
+#[cfg(any(
+    feature = "extra_cookie_signed",
+    feature = "extra_cookie_private"
+))]
+pub use axum_extra::extract::cookie::Key;
+
+pub use crate::response::cookie::{Cookie, Expiration, SameSite};
+
+use std::convert::Infallible;
+
+use axum_core::{
+    extract::FromRequestParts,
+    response::{IntoResponse, IntoResponseParts, ResponseParts},
+};
+use axum_extra::extract::cookie::CookieJar as _CookieJar;
+use http::{HeaderMap, request::Parts};
+
+use crate::{
+    error::JderError,
+    response::{Response, json::JsonResponseErrorCode},
+};
+
+/// Create the error for a missing cookie.
+pub(crate) fn create_missing_cookie_error(name: &str) -> JderError {
+    JderError::new(JsonResponseErrorCode::BadRequest)
+        .with_field(name)
+        .with_message("Missing cookie.")
+}
+
+/// Create the error for a cookie that fails verification.
+#[cfg(any(feature = "extra_cookie_signed", feature = "extra_cookie_private"))]
+pub(crate) fn create_invalid_cookie_error(name: &str) -> JderError {
+    JderError::new(JsonResponseErrorCode::Parse)
+        .with_field(name)
+        .with_message("Invalid cookie.")
+}
+
+/// Extractor and response for managing cookies.
+///
+/// Use [`require`](Self::require) to get a cookie
+/// or send a failure response with status code `400`
+/// and error code `bad_request` if it is missing.
+///
+/// Check [`CookieJar`](axum_extra::extract::cookie::CookieJar)
+/// for more information.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     error::JderError,
+///     extract::extra::cookie::{Cookie, CookieJar},
+/// };
+///
+/// async fn route(jar: CookieJar) -> Result<(CookieJar, String), JderError> {
+///     let theme: Cookie<'static> = jar.require("theme")?;
+///     let theme: String = theme.value().to_string();
+///
+///     Ok((jar.add(Cookie::new("visited", "true")), theme))
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CookieJar(pub _CookieJar);
+
+impl CookieJar {
+    /// Create a new `CookieJar` from a map of request headers.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::HeaderMap;
+    /// use jder_axum::extract::extra::cookie::CookieJar;
+    ///
+    /// let jar: CookieJar = CookieJar::from_headers(&HeaderMap::new());
+    /// ```
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        Self(_CookieJar::from_headers(headers))
+    }
+
+    /// Get a cookie from the jar.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::extract::extra::cookie::CookieJar;
+    ///
+    /// async fn route(jar: CookieJar) {
+    ///     let theme: Option<String> =
+    ///         jar.get("theme").map(|cookie| cookie.value().to_string());
+    /// }
+    /// ```
+    pub fn get(
+        &self,
+        name: &str,
+    ) -> Option<Cookie<'static>> {
+        self.0.get(name).cloned()
+    }
+
+    /// Get a cookie from the jar,
+    /// or returns an error with status code `400`
+    /// and error code `bad_request` if it is missing.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     extract::extra::cookie::CookieJar,
+    /// };
+    ///
+    /// async fn route(jar: CookieJar) -> Result<String, JderError> {
+    ///     Ok(jar.require("theme")?.value().to_string())
+    /// }
+    /// ```
+    pub fn require(
+        &self,
+        name: &str,
+    ) -> Result<Cookie<'static>, JderError> {
+        self.get(name).ok_or_else(|| create_missing_cookie_error(name))
+    }
+
+    /// Add a cookie to the jar,
+    /// which will be set when the jar is returned as a response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::extract::extra::cookie::{Cookie, CookieJar};
+    ///
+    /// async fn route(jar: CookieJar) -> CookieJar {
+    ///     jar.add(Cookie::new("theme", "dark"))
+    /// }
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        Self(self.0.add(cookie))
+    }
+
+    /// Remove a cookie from the jar,
+    /// which will be removed on the client when the jar is returned as a response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::extract::extra::cookie::CookieJar;
+    ///
+    /// async fn route(jar: CookieJar) -> CookieJar {
+    ///     jar.remove("theme")
+    /// }
+    /// ```
+    pub fn remove<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        Self(self.0.remove(cookie))
+    }
+
+    /// Get an iterator over all cookies in the jar.
+    pub fn iter(&self) -> impl Iterator<Item = &Cookie<'static>> {
+        self.0.iter()
+    }
+}
+
+impl<S> FromRequestParts<S> for CookieJar
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        Ok(Self::from_headers(&parts.headers))
+    }
+}
+
+axum_core::__impl_deref!(CookieJar: _CookieJar);
+
+impl IntoResponseParts for CookieJar {
+    type Error = Infallible;
+
+    fn into_response_parts(
+        self,
+        res: ResponseParts,
+    ) -> Result<ResponseParts, Self::Error> {
+        self.0.into_response_parts(res)
+    }
+}
+
+impl IntoResponse for CookieJar {
+    fn into_response(self) -> Response {
+        self.0.into_response()
+    }
+}
@@ -0,0 +1,200 @@
+use std::convert::Infallible;
+
+use axum_core::{
+    extract::{FromRef, FromRequestParts},
+    response::{IntoResponse, IntoResponseParts, ResponseParts},
+};
+use axum_extra::extract::cookie::{
+    CookieJar as _CookieJar, Key, PrivateCookieJar as _PrivateCookieJar,
+};
+use http::request::Parts;
+
+use crate::{
+    error::JderError,
+    extract::extra::cookie::{
+        Cookie, create_invalid_cookie_error, create_missing_cookie_error,
+    },
+    response::Response,
+};
+
+/// Extractor and response for managing private cookies,
+/// which are encrypted and cannot be read or modified by the client.
+///
+/// Use [`require`](Self::require) to get a decrypted cookie,
+/// or send a failure response with status code `400`
+/// and error code `bad_request` if it is missing,
+/// or error code `parse` if it fails decryption.
+///
+/// The key is extracted from the state with [`FromRef`].
+///
+/// Check [`PrivateCookieJar`](axum_extra::extract::cookie::PrivateCookieJar)
+/// for more information.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, routing::get};
+/// use jder_axum::{
+///     error::JderError,
+///     extract::extra::cookie::{Key, PrivateCookieJar},
+/// };
+///
+/// async fn route(jar: PrivateCookieJar) -> Result<String, JderError> {
+///     Ok(jar.require("session")?.value().to_string())
+/// }
+///
+/// let app: Router = Router::new()
+///     .route("/", get(route))
+///     .with_state(Key::generate());
+/// ```
+pub struct PrivateCookieJar<K = Key> {
+    jar: _PrivateCookieJar<K>,
+    raw: _CookieJar,
+}
+
+impl<K> PrivateCookieJar<K> {
+    /// Get a decrypted cookie from the jar.
+    pub fn get(
+        &self,
+        name: &str,
+    ) -> Option<Cookie<'static>> {
+        self.jar.get(name)
+    }
+
+    /// Get a decrypted cookie from the jar,
+    /// or returns an error with status code `400`
+    /// and error code `bad_request` if it is missing,
+    /// or error code `parse` if it fails decryption.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     extract::extra::cookie::PrivateCookieJar,
+    /// };
+    ///
+    /// async fn route(jar: PrivateCookieJar) -> Result<String, JderError> {
+    ///     Ok(jar.require("session")?.value().to_string())
+    /// }
+    /// ```
+    pub fn require(
+        &self,
+        name: &str,
+    ) -> Result<Cookie<'static>, JderError> {
+        match (self.jar.get(name), self.raw.get(name)) {
+            | (Some(cookie), _) => Ok(cookie),
+            | (None, Some(_)) => Err(create_invalid_cookie_error(name)),
+            | (None, None) => Err(create_missing_cookie_error(name)),
+        }
+    }
+
+    /// Add a cookie to the jar, encrypted with the key.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::extract::extra::cookie::{Cookie, PrivateCookieJar};
+    ///
+    /// async fn route(jar: PrivateCookieJar) -> PrivateCookieJar {
+    ///     jar.add(Cookie::new("session", "value"))
+    /// }
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        let cookie: Cookie<'static> = cookie.into();
+
+        Self { raw: self.raw.add(cookie.clone()), jar: self.jar.add(cookie) }
+    }
+
+    /// Remove a cookie from the jar.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::extract::extra::cookie::PrivateCookieJar;
+    ///
+    /// async fn route(jar: PrivateCookieJar) -> PrivateCookieJar {
+    ///     jar.remove("session")
+    /// }
+    /// ```
+    pub fn remove<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        let cookie: Cookie<'static> = cookie.into();
+
+        Self {
+            raw: self.raw.remove(cookie.clone()),
+            jar: self.jar.remove(cookie),
+        }
+    }
+
+    /// Get an iterator over all decrypted cookies in the jar.
+    pub fn iter(&self) -> impl Iterator<Item = Cookie<'static>> + '_ {
+        self.jar.iter()
+    }
+
+    /// Get the inner [`PrivateCookieJar`](axum_extra::extract::cookie::PrivateCookieJar).
+    pub fn into_inner(self) -> _PrivateCookieJar<K> {
+        self.jar
+    }
+}
+
+impl<K> std::fmt::Debug for PrivateCookieJar<K> {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_struct("PrivateCookieJar").field("jar", &self.jar).finish()
+    }
+}
+
+impl<K> Clone for PrivateCookieJar<K> {
+    fn clone(&self) -> Self {
+        Self { jar: self.jar.clone(), raw: self.raw.clone() }
+    }
+}
+
+impl<S, K> FromRequestParts<S> for PrivateCookieJar<K>
+where
+    S: Send + Sync,
+    K: FromRef<S> + Into<Key>,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        let raw: _CookieJar = _CookieJar::from_headers(&parts.headers);
+
+        let jar: _PrivateCookieJar<K> =
+            <_PrivateCookieJar<K> as FromRequestParts<S>>::from_request_parts(
+                parts, state,
+            )
+            .await?;
+
+        Ok(Self { jar, raw })
+    }
+}
+
+impl<K> IntoResponseParts for PrivateCookieJar<K> {
+    type Error = Infallible;
+
+    fn into_response_parts(
+        self,
+        res: ResponseParts,
+    ) -> Result<ResponseParts, Self::Error> {
+        self.jar.into_response_parts(res)
+    }
+}
+
+impl<K> IntoResponse for PrivateCookieJar<K> {
+    fn into_response(self) -> Response {
+        self.jar.into_response()
+    }
+}
@@ -0,0 +1,200 @@
+use std::convert::Infallible;
+
+use axum_core::{
+    extract::{FromRef, FromRequestParts},
+    response::{IntoResponse, IntoResponseParts, ResponseParts},
+};
+use axum_extra::extract::cookie::{
+    CookieJar as _CookieJar, Key, SignedCookieJar as _SignedCookieJar,
+};
+use http::request::Parts;
+
+use crate::{
+    error::JderError,
+    extract::extra::cookie::{
+        Cookie, create_invalid_cookie_error, create_missing_cookie_error,
+    },
+    response::Response,
+};
+
+/// Extractor and response for managing signed cookies,
+/// which can be read by the client but not modified.
+///
+/// Use [`require`](Self::require) to get a verified cookie,
+/// or send a failure response with status code `400`
+/// and error code `bad_request` if it is missing,
+/// or error code `parse` if it fails verification.
+///
+/// The key is extracted from the state with [`FromRef`].
+///
+/// Check [`SignedCookieJar`](axum_extra::extract::cookie::SignedCookieJar)
+/// for more information.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, routing::get};
+/// use jder_axum::{
+///     error::JderError,
+///     extract::extra::cookie::{Key, SignedCookieJar},
+/// };
+///
+/// async fn route(jar: SignedCookieJar) -> Result<String, JderError> {
+///     Ok(jar.require("session")?.value().to_string())
+/// }
+///
+/// let app: Router = Router::new()
+///     .route("/", get(route))
+///     .with_state(Key::generate());
+/// ```
+pub struct SignedCookieJar<K = Key> {
+    jar: _SignedCookieJar<K>,
+    raw: _CookieJar,
+}
+
+impl<K> SignedCookieJar<K> {
+    /// Get a verified cookie from the jar.
+    pub fn get(
+        &self,
+        name: &str,
+    ) -> Option<Cookie<'static>> {
+        self.jar.get(name)
+    }
+
+    /// Get a verified cookie from the jar,
+    /// or returns an error with status code `400`
+    /// and error code `bad_request` if it is missing,
+    /// or error code `parse` if it fails verification.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     extract::extra::cookie::SignedCookieJar,
+    /// };
+    ///
+    /// async fn route(jar: SignedCookieJar) -> Result<String, JderError> {
+    ///     Ok(jar.require("session")?.value().to_string())
+    /// }
+    /// ```
+    pub fn require(
+        &self,
+        name: &str,
+    ) -> Result<Cookie<'static>, JderError> {
+        match (self.jar.get(name), self.raw.get(name)) {
+            | (Some(cookie), _) => Ok(cookie),
+            | (None, Some(_)) => Err(create_invalid_cookie_error(name)),
+            | (None, None) => Err(create_missing_cookie_error(name)),
+        }
+    }
+
+    /// Add a cookie to the jar, signed with the key.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::extract::extra::cookie::{Cookie, SignedCookieJar};
+    ///
+    /// async fn route(jar: SignedCookieJar) -> SignedCookieJar {
+    ///     jar.add(Cookie::new("session", "value"))
+    /// }
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        let cookie: Cookie<'static> = cookie.into();
+
+        Self { raw: self.raw.add(cookie.clone()), jar: self.jar.add(cookie) }
+    }
+
+    /// Remove a cookie from the jar.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::extract::extra::cookie::SignedCookieJar;
+    ///
+    /// async fn route(jar: SignedCookieJar) -> SignedCookieJar {
+    ///     jar.remove("session")
+    /// }
+    /// ```
+    pub fn remove<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        let cookie: Cookie<'static> = cookie.into();
+
+        Self {
+            raw: self.raw.remove(cookie.clone()),
+            jar: self.jar.remove(cookie),
+        }
+    }
+
+    /// Get an iterator over all verified cookies in the jar.
+    pub fn iter(&self) -> impl Iterator<Item = Cookie<'static>> + '_ {
+        self.jar.iter()
+    }
+
+    /// Get the inner [`SignedCookieJar`](axum_extra::extract::cookie::SignedCookieJar).
+    pub fn into_inner(self) -> _SignedCookieJar<K> {
+        self.jar
+    }
+}
+
+impl<K> std::fmt::Debug for SignedCookieJar<K> {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_struct("SignedCookieJar").field("jar", &self.jar).finish()
+    }
+}
+
+impl<K> Clone for SignedCookieJar<K> {
+    fn clone(&self) -> Self {
+        Self { jar: self.jar.clone(), raw: self.raw.clone() }
+    }
+}
+
+impl<S, K> FromRequestParts<S> for SignedCookieJar<K>
+where
+    S: Send + Sync,
+    K: FromRef<S> + Into<Key>,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        let raw: _CookieJar = _CookieJar::from_headers(&parts.headers);
+
+        let jar: _SignedCookieJar<K> =
+            <_SignedCookieJar<K> as FromRequestParts<S>>::from_request_parts(
+                parts, state,
+            )
+            .await?;
+
+        Ok(Self { jar, raw })
+    }
+}
+
+impl<K> IntoResponseParts for SignedCookieJar<K> {
+    type Error = Infallible;
+
+    fn into_response_parts(
+        self,
+        res: ResponseParts,
+    ) -> Result<ResponseParts, Self::Error> {
+        self.jar.into_response_parts(res)
+    }
+}
+
+impl<K> IntoResponse for SignedCookieJar<K> {
+    fn into_response(self) -> Response {
+        self.jar.into_response()
+    }
+}
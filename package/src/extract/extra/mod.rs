@@ -1,5 +1,20 @@
 pub mod host;
 
+/// Cookie extractor module,
+/// available with `extra_cookie` feature.
+#[cfg(feature = "extra_cookie")]
+pub mod cookie;
+
+/// Precondition extractor module,
+/// available with `extra_typed_header` feature.
+#[cfg(feature = "extra_typed_header")]
+pub mod precondition;
+
+/// Request base extractor module,
+/// available with `extra_scheme` feature.
+#[cfg(feature = "extra_scheme")]
+pub mod request_base;
+
 /// Scheme extractor module,
 /// available with `extra_scheme` feature.
 #[cfg(feature = "extra_scheme")]
@@ -12,6 +27,21 @@ pub mod typed_header;
 
 pub use crate::extract::extra::host::Host;
 
+#[cfg(feature = "extra_cookie")]
+pub use crate::extract::extra::cookie::CookieJar;
+
+#[cfg(feature = "extra_cookie_signed")]
+pub use crate::extract::extra::cookie::SignedCookieJar;
+
+#[cfg(feature = "extra_cookie_private")]
+pub use crate::extract::extra::cookie::PrivateCookieJar;
+
+#[cfg(feature = "extra_typed_header")]
+pub use crate::extract::extra::precondition::{IfMatch, IfUnmodifiedSince};
+
+#[cfg(feature = "extra_scheme")]
+pub use crate::extract::extra::request_base::RequestBase;
+
 #[cfg(feature = "extra_scheme")]
 pub use crate::extract::extra::scheme::Scheme;
 
@@ -0,0 +1,209 @@
+use std::time::SystemTime;
+
+use axum_core::extract::FromRequestParts;
+use headers::{
+    ETag, Header, IfMatch as _IfMatch, IfUnmodifiedSince as _IfUnmodifiedSince,
+};
+use http::{HeaderName, StatusCode, request::Parts};
+
+use crate::{
+    error::JderError,
+    response::{
+        Response,
+        json::{CreateJsonResponse, error::JsonResponseErrorCode},
+    },
+};
+
+/// Decode a precondition header from the request,
+/// or send a failure response with status code `428`
+/// and error code `precondition_required` if it is missing.
+#[allow(clippy::result_large_err)]
+fn decode_precondition<H: Header>(parts: &Parts) -> Result<H, Response> {
+    let name: &HeaderName = H::name();
+
+    let mut values = parts.headers.get_all(name).iter().peekable();
+
+    if values.peek().is_none() {
+        return Err(CreateJsonResponse::failure()
+            .code(JsonResponseErrorCode::PreconditionRequired)
+            .error_field(name.as_str())
+            .error_message(format!("Missing `{}` header.", name))
+            .send());
+    }
+
+    H::decode(&mut values).map_err(|_| {
+        CreateJsonResponse::failure()
+            .status(StatusCode::BAD_REQUEST)
+            .error_code(JsonResponseErrorCode::Parse.as_str())
+            .error_field(name.as_str())
+            .error_message(format!("Invalid `{}` header.", name))
+            .send()
+    })
+}
+
+/// Create the error for a failed precondition.
+fn create_precondition_failed_error(name: &HeaderName) -> JderError {
+    JderError::new(JsonResponseErrorCode::PreconditionFailed)
+        .with_field(name.as_str())
+        .with_message("Resource has been modified.")
+}
+
+/// Extractor for the `If-Match` header,
+/// for rejecting writes against stale versions of a resource.
+///
+/// If the header is missing, a failure response with status code `428`
+/// and error code `precondition_required` will be sent.
+///
+/// Use [`check`](Self::check) to compare it with the current `ETag`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use headers::ETag;
+/// use jder_axum::{
+///     error::JderError,
+///     extract::extra::IfMatch,
+/// };
+///
+/// async fn route(if_match: IfMatch) -> Result<(), JderError> {
+///     let current: ETag = "\"v2\"".parse().unwrap();
+///
+///     // 412 if the client has an outdated version
+///     if_match.check(&current)?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct IfMatch(pub _IfMatch);
+
+impl IfMatch {
+    /// Check if the current `ETag` matches with the strong comparison,
+    /// or returns an error with status code `412`
+    /// and error code `precondition_failed`.
+    ///
+    /// Weak `ETag` never matches as required by RFC 9110,
+    /// so the current `ETag` should be strong.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use headers::ETag;
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     extract::extra::IfMatch,
+    /// };
+    ///
+    /// async fn route(if_match: IfMatch) -> Result<(), JderError> {
+    ///     if_match.check(&"\"v2\"".parse::<ETag>().unwrap())
+    /// }
+    /// ```
+    pub fn check(
+        &self,
+        current: &ETag,
+    ) -> Result<(), JderError> {
+        match self.0.precondition_passes(current) {
+            | true => Ok(()),
+            | false => {
+                Err(create_precondition_failed_error(&http::header::IF_MATCH))
+            },
+        }
+    }
+
+    /// Check if the header is `*`.
+    pub fn is_any(&self) -> bool {
+        self.0.is_any()
+    }
+}
+
+impl<S> FromRequestParts<S> for IfMatch
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        decode_precondition::<_IfMatch>(parts).map(Self)
+    }
+}
+
+/// Extractor for the `If-Unmodified-Since` header,
+/// for rejecting writes against stale versions of a resource.
+///
+/// If the header is missing, a failure response with status code `428`
+/// and error code `precondition_required` will be sent.
+///
+/// Use [`check`](Self::check) to compare it with the last modified time.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::SystemTime;
+///
+/// use jder_axum::{
+///     error::JderError,
+///     extract::extra::IfUnmodifiedSince,
+/// };
+///
+/// async fn route(
+///     if_unmodified_since: IfUnmodifiedSince,
+/// ) -> Result<(), JderError> {
+///     // 412 if the resource has been modified since
+///     if_unmodified_since.check(SystemTime::UNIX_EPOCH)?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IfUnmodifiedSince(pub _IfUnmodifiedSince);
+
+impl IfUnmodifiedSince {
+    /// Check if the resource is not modified since the time in the header,
+    /// or returns an error with status code `412`
+    /// and error code `precondition_failed`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::SystemTime;
+    ///
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     extract::extra::IfUnmodifiedSince,
+    /// };
+    ///
+    /// async fn route(
+    ///     if_unmodified_since: IfUnmodifiedSince,
+    /// ) -> Result<(), JderError> {
+    ///     if_unmodified_since.check(SystemTime::UNIX_EPOCH)
+    /// }
+    /// ```
+    pub fn check(
+        &self,
+        last_modified: SystemTime,
+    ) -> Result<(), JderError> {
+        match self.0.precondition_passes(last_modified) {
+            | true => Ok(()),
+            | false => Err(create_precondition_failed_error(
+                &http::header::IF_UNMODIFIED_SINCE,
+            )),
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for IfUnmodifiedSince
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        decode_precondition::<_IfUnmodifiedSince>(parts).map(Self)
+    }
+}
@@ -0,0 +1,255 @@
+use axum::extract::OriginalUri;
+use axum_core::extract::FromRequestParts;
+use axum_extra::extract::{Host as _Host, Scheme as _Scheme};
+use http::{Uri, header, request::Parts};
+
+use crate::{
+    error::JderError,
+    response::{
+        Response,
+        header::is_valid_location,
+        json::{CreateJsonResponse, error::JsonResponseErrorCode},
+    },
+};
+
+/// Extractor that resolves the base URL of a request
+/// from the scheme, host and path,
+/// for resolving relative locations of redirects.
+///
+/// The scheme is resolved as in [`Scheme`](crate::extract::extra::Scheme),
+/// and falls back to `http` if it cannot be resolved.
+/// The host is resolved as in [`Host`](crate::extract::extra::Host).
+/// The path is the path of the request before nesting.
+///
+/// Note that user agents can set the `X-Forwarded-Proto`
+/// and `X-Forwarded-Host` headers to arbitrary values so make
+/// sure to validate them to avoid security issues.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     error::JderError,
+///     extract::extra::RequestBase,
+///     response::{
+///         Response,
+///         CreateResponse,
+///     },
+/// };
+///
+/// async fn route(base: RequestBase) -> Result<Response, JderError> {
+///     // e.g. `https://example.com/users/1` for `/users/`
+///     let location: String = base.resolve("1")?;
+///
+///     Ok(CreateResponse::redirect_see_other(&location).body(()))
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestBase {
+    /// Scheme of the request, e.g. `https`.
+    pub scheme: String,
+    /// Host of the request with the port if any, e.g. `example.com`.
+    pub host: String,
+    /// Path of the request with the query if any, e.g. `/users?page=1`.
+    pub path: String,
+}
+
+impl RequestBase {
+    /// Resolve a location against the request into an absolute URL,
+    /// as a reference is resolved against a base URL in RFC 3986.
+    ///
+    /// Absolute `http` / `https` URLs are returned as is,
+    /// network paths such as `//example.com/users` take the scheme,
+    /// and other references take the scheme and host,
+    /// e.g. for the path `/users/1`:
+    ///
+    /// - `/teams` resolves to `/teams`
+    /// - `2` resolves to `/users/2`
+    /// - `../teams` resolves to `/teams`
+    /// - `?page=2` resolves to `/users/1?page=2`
+    ///
+    /// URLs with other schemes such as `javascript:`
+    /// or characters not allowed in URIs
+    /// returns an error with status code `400` and error code `parse`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::extract::extra::RequestBase;
+    ///
+    /// let base: RequestBase = RequestBase {
+    ///     scheme: "https".to_string(),
+    ///     host: "example.com".to_string(),
+    ///     path: "/users/1".to_string(),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     base.resolve("../teams").unwrap(),
+    ///     "https://example.com/teams"
+    /// );
+    /// ```
+    pub fn resolve(
+        &self,
+        location: &str,
+    ) -> Result<String, JderError> {
+        let allowed: bool =
+            location.bytes().all(|b| b.is_ascii_graphic() && b != b'\\');
+
+        let resolved: Option<String> = match allowed {
+            | true => self.resolve_reference(location),
+            | false => None,
+        };
+
+        match resolved {
+            | Some(resolved) if is_valid_location(&resolved) => Ok(resolved),
+            | _ => Err(JderError::new(JsonResponseErrorCode::Parse)
+                .with_field(header::LOCATION.as_str())
+                .with_message(format!("Invalid location: {}", location))),
+        }
+    }
+
+    /// Resolve a reference as in RFC 3986 section 5.2.2,
+    /// returns `None` if it has a scheme other than `http` / `https`.
+    fn resolve_reference(
+        &self,
+        location: &str,
+    ) -> Option<String> {
+        if has_scheme(location) {
+            return is_valid_location(location).then(|| location.to_string());
+        }
+
+        if location.starts_with("//") {
+            return Some(format!("{}:{}", self.scheme, location));
+        }
+
+        let (base_path, base_query): (&str, Option<&str>) =
+            split_once_opt(&self.path, '?');
+        let (reference, fragment): (&str, Option<&str>) =
+            split_once_opt(location, '#');
+        let (path, query): (&str, Option<&str>) =
+            split_once_opt(reference, '?');
+
+        let (path, query): (String, Option<&str>) = match path {
+            | "" => (base_path.to_string(), query.or(base_query)),
+            | path if path.starts_with('/') => {
+                (remove_dot_segments(path), query)
+            },
+            | path => {
+                let dir: &str = match base_path.rfind('/') {
+                    | Some(index) => &base_path[..=index],
+                    | None => "/",
+                };
+
+                (remove_dot_segments(&format!("{}{}", dir, path)), query)
+            },
+        };
+
+        let mut resolved: String =
+            format!("{}://{}{}", self.scheme, self.host, path);
+
+        if let Some(query) = query {
+            resolved.push('?');
+            resolved.push_str(query);
+        }
+
+        if let Some(fragment) = fragment {
+            resolved.push('#');
+            resolved.push_str(fragment);
+        }
+
+        Some(resolved)
+    }
+}
+
+/// Check if a reference starts with a scheme, e.g. `https:`.
+fn has_scheme(location: &str) -> bool {
+    let scheme: &str = match location.split_once(':') {
+        | Some((scheme, _)) => scheme,
+        | None => return false,
+    };
+
+    scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Split a string at the first occurrence of a delimiter,
+/// keeping the whole string if it is not found.
+fn split_once_opt(
+    value: &str,
+    delimiter: char,
+) -> (&str, Option<&str>) {
+    match value.split_once(delimiter) {
+        | Some((head, tail)) => (head, Some(tail)),
+        | None => (value, None),
+    }
+}
+
+/// Remove `.` and `..` segments from an absolute path
+/// as in RFC 3986 section 5.2.4.
+fn remove_dot_segments(path: &str) -> String {
+    let segments: Vec<&str> = path.split('/').skip(1).collect();
+    let mut output: Vec<&str> = Vec::new();
+
+    for (index, segment) in segments.iter().enumerate() {
+        let last: bool = index + 1 == segments.len();
+
+        match *segment {
+            | "." => {},
+            | ".." => {
+                output.pop();
+            },
+            | segment => output.push(segment),
+        }
+
+        // keep the trailing slash of `/users/.` and `/users/..`
+        if last && matches!(*segment, "." | "..") {
+            output.push("");
+        }
+    }
+
+    format!("/{}", output.join("/"))
+}
+
+impl<S> FromRequestParts<S> for RequestBase
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        let host: String = match _Host::from_request_parts(parts, state).await {
+            | Ok(val) => val.0,
+            | Err(rej) => {
+                return Err(CreateJsonResponse::failure()
+                    .status(rej.status())
+                    .error_code(JsonResponseErrorCode::Parse.as_str())
+                    .error_message(rej.body_text())
+                    .send());
+            },
+        };
+
+        let scheme: String =
+            match _Scheme::from_request_parts(parts, state).await {
+                | Ok(val) => val.0,
+                | Err(_) => "http".to_string(),
+            };
+
+        // path before nesting if the router is nested
+        let uri: &Uri = match parts.extensions.get::<OriginalUri>() {
+            | Some(uri) => &uri.0,
+            | None => &parts.uri,
+        };
+
+        let path: String = match uri.path_and_query() {
+            | Some(path) => path.to_string(),
+            | None => uri.path().to_string(),
+        };
+
+        Ok(Self { scheme, host, path })
+    }
+}
@@ -3,13 +3,15 @@ use axum_core::{
     extract::{FromRequest, OptionalFromRequest, Request},
     response::IntoResponse,
 };
-use bytes::{BufMut, BytesMut, buf::Writer};
-use http::{StatusCode, header};
+use http::{HeaderValue, StatusCode, header};
 use serde::{Serialize, de::DeserializeOwned};
 
 use crate::response::{
-    CreateResponse, Response,
-    json::{CreateJsonResponse, JsonResponseErrorCode},
+    Response,
+    json::{
+        CreateJsonResponse, JsonResponseErrorCode,
+        config::{JsonOutput, create_json_body},
+    },
 };
 
 /// JSON extractor / response.
@@ -121,6 +123,8 @@ where
     /// Construct a `Json<T>` from a byte slice. Most users should prefer to use the `FromRequest` impl
     /// but special cases may require first extracting a `Request` into `Bytes` then optionally
     /// constructing a `Json<T>`.
+    // the failure response is returned as is to keep the public signature
+    #[allow(clippy::result_large_err)]
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, Response> {
         match _Json::<T>::from_bytes(bytes) {
             | Ok(val) => Ok(Self(val.0)),
@@ -135,15 +139,20 @@ where
 
 impl<T> IntoResponse for Json<T>
 where
-    T: Serialize,
+    T: Serialize + Send + 'static,
 {
     fn into_response(self) -> Response {
-        let mut buf: Writer<BytesMut> = BytesMut::with_capacity(128).writer();
+        match create_json_body(self.0, |data: &T, output: &JsonOutput| {
+            output.data_to_bytes(data)
+        }) {
+            | Ok(mut res) => {
+                res.headers_mut().insert(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static("application/json"),
+                );
 
-        match serde_json::to_writer(&mut buf, &self.0) {
-            | Ok(_) => CreateResponse::success()
-                .header(header::CONTENT_TYPE, "application/json")
-                .body(buf.into_inner().freeze()),
+                res
+            },
             | Err(err) => CreateJsonResponse::failure()
                 .status(StatusCode::INTERNAL_SERVER_ERROR)
                 .error_code(JsonResponseErrorCode::Server.as_str())
@@ -21,6 +21,11 @@ pub mod matched_path;
 #[cfg(feature = "multipart")]
 pub mod multipart;
 
+/// Negotiate extractor module,
+/// available with `negotiate` feature.
+#[cfg(feature = "negotiate")]
+pub mod negotiate;
+
 /// Query extractor module,
 /// available with `query` feature.
 #[cfg(feature = "query")]
@@ -48,6 +53,9 @@ pub use crate::extract::json::Json;
 #[cfg(feature = "matched_path")]
 pub use crate::extract::matched_path::MatchedPath;
 
+#[cfg(feature = "negotiate")]
+pub use crate::extract::negotiate::Negotiate;
+
 #[cfg(feature = "query")]
 pub use crate::extract::query::Query;
 
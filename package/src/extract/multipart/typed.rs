@@ -1,22 +1,12 @@
-use axum_core::response::{IntoResponse, Response as AxumResponse};
 use axum_typed_multipart::{BaseMultipart, TypedMultipartError};
 
-use crate::{
-    extract::json::Json,
-    response::json::{
-        JsonResponse, JsonResponseError, error::JsonResponseErrorCode,
-    },
+use crate::response::json::{
+    JsonResponse, JsonResponseError, error::JsonResponseErrorCode,
 };
 
 /// Multipart failure response.
 pub type TypedMultipartFailureResponse = JsonResponse<()>;
 
-impl IntoResponse for TypedMultipartFailureResponse {
-    fn into_response(self) -> AxumResponse {
-        Json(self).into_response()
-    }
-}
-
 impl From<TypedMultipartError> for TypedMultipartFailureResponse {
     fn from(error: TypedMultipartError) -> Self {
         Self {
@@ -27,6 +17,8 @@ impl From<TypedMultipartError> for TypedMultipartFailureResponse {
                 field: None,
                 message: Some(error.to_string()),
             }),
+            errors: Vec::new(),
+            pagination: None,
         }
     }
 }
@@ -0,0 +1,271 @@
+use axum_core::extract::FromRequestParts;
+use http::{HeaderMap, header, request::Parts};
+
+use crate::{
+    error::JderError,
+    response::{
+        Response,
+        json::{
+            CreateJsonResponse, JsonResponse, Negotiated, NegotiatedFormat,
+        },
+    },
+};
+
+/// Get the quality of a format in the `Accept` header,
+/// from the most specific matching media range.
+fn get_format_quality(
+    headers: &HeaderMap,
+    format: NegotiatedFormat,
+) -> f32 {
+    let mut best: Option<(u8, f32)> = None;
+
+    let ranges = headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','));
+
+    for range in ranges {
+        let mut params = range.split(';');
+
+        let mime: &str = match params.next() {
+            | Some(mime) => mime.trim(),
+            | None => continue,
+        };
+
+        let specificity: u8 = if format.matches(mime) {
+            2
+        } else if mime.eq_ignore_ascii_case("application/*") {
+            1
+        } else if mime == "*/*" {
+            0
+        } else {
+            continue;
+        };
+
+        let quality: f32 = params
+            .filter_map(|param| param.split_once('='))
+            .find(|(key, _)| key.trim().eq_ignore_ascii_case("q"))
+            .map(|(_, value)| value.trim().parse::<f32>().unwrap_or(0.0))
+            .unwrap_or(1.0);
+
+        best = match best {
+            | Some((prev, _)) if prev < specificity => {
+                Some((specificity, quality))
+            },
+            | Some((prev, prev_quality)) if prev == specificity => {
+                Some((specificity, prev_quality.max(quality)))
+            },
+            | Some(best) => Some(best),
+            | None => Some((specificity, quality)),
+        };
+    }
+
+    best.map_or(0.0, |(_, quality)| quality)
+}
+
+/// Negotiate a format from the `Accept` header,
+/// preferring earlier formats of [`NegotiatedFormat::ALL`]
+/// when accepted equally.
+fn negotiate_format(headers: &HeaderMap) -> Option<NegotiatedFormat> {
+    let has_accept: bool = headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .any(|value| !value.as_bytes().trim_ascii().is_empty());
+
+    if !has_accept {
+        return Some(NegotiatedFormat::default());
+    }
+
+    let mut best: Option<(NegotiatedFormat, f32)> = None;
+
+    for format in NegotiatedFormat::ALL {
+        let quality: f32 = get_format_quality(headers, format);
+
+        if quality > 0.0 && best.is_none_or(|(_, prev)| quality > prev) {
+            best = Some((format, quality));
+        }
+    }
+
+    best.map(|(format, _)| format)
+}
+
+/// Extractor that negotiates the format of the response
+/// from the `Accept` header,
+/// with JSON, MessagePack and CBOR supported.
+///
+/// JSON is used if the header is missing
+/// or formats are accepted equally.
+/// If no format is acceptable,
+/// a failure response with status code `406`
+/// and error code `not_acceptable` will be sent.
+///
+/// The response keeps the JDER structure in every format,
+/// and [`JsonResponseConfig`](crate::response::json::JsonResponseConfig)
+/// only applies to JSON.
+///
+/// Available with `negotiate` feature.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     extract::Negotiate,
+///     response::json::Negotiated,
+/// };
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct User {
+///     id: usize,
+/// }
+///
+/// async fn route(negotiate: Negotiate) -> Negotiated<User> {
+///     // `application/json`, `application/msgpack` or `application/cbor`
+///     println!("{}", negotiate.media_type());
+///
+///     negotiate.ok(User { id: 1 })
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Negotiate(pub NegotiatedFormat);
+
+impl Negotiate {
+    /// Get the negotiated format.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     extract::Negotiate,
+    ///     response::json::NegotiatedFormat,
+    /// };
+    ///
+    /// async fn route(negotiate: Negotiate) {
+    ///     let is_json: bool = negotiate.format() == NegotiatedFormat::Json;
+    /// }
+    /// ```
+    pub fn format(&self) -> NegotiatedFormat {
+        self.0
+    }
+
+    /// Get the media type of the negotiated format,
+    /// e.g. `application/msgpack`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::extract::Negotiate;
+    ///
+    /// async fn route(negotiate: Negotiate) -> String {
+    ///     negotiate.media_type().to_string()
+    /// }
+    /// ```
+    pub fn media_type(&self) -> &'static str {
+        self.0.media_type()
+    }
+
+    /// Create a response in the negotiated format.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     extract::Negotiate,
+    ///     response::json::{JsonResponse, Negotiated},
+    /// };
+    ///
+    /// async fn route(negotiate: Negotiate) -> Negotiated {
+    ///     negotiate.send(JsonResponse {
+    ///         success: true,
+    ///         ..Default::default()
+    ///     })
+    /// }
+    /// ```
+    pub fn send<D>(
+        &self,
+        response: JsonResponse<D>,
+    ) -> Negotiated<D> {
+        Negotiated::new(self.0, response)
+    }
+
+    /// Create a success response with data in the negotiated format.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     extract::Negotiate,
+    ///     response::json::Negotiated,
+    /// };
+    ///
+    /// async fn route(negotiate: Negotiate) -> Negotiated<String> {
+    ///     negotiate.ok("Name".to_string())
+    /// }
+    /// ```
+    pub fn ok<D>(
+        &self,
+        data: D,
+    ) -> Negotiated<D> {
+        self.send(JsonResponse {
+            success: true,
+            data: Some(data),
+            error: None,
+            errors: Vec::new(),
+            pagination: None,
+        })
+    }
+
+    /// Create a failure response from an error in the negotiated format,
+    /// with the status code of the error.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     extract::Negotiate,
+    ///     response::json::{JsonResponseErrorCode, Negotiated},
+    /// };
+    ///
+    /// async fn route(negotiate: Negotiate) -> Negotiated {
+    ///     negotiate.error(JderError::new(JsonResponseErrorCode::NotFound))
+    /// }
+    /// ```
+    pub fn error<E: Into<JderError>>(
+        &self,
+        error: E,
+    ) -> Negotiated {
+        Negotiated::from_error(self.0, error.into())
+    }
+}
+
+impl<S> FromRequestParts<S> for Negotiate
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        match negotiate_format(&parts.headers) {
+            | Some(format) => Ok(Self(format)),
+            | None => {
+                let supported: Vec<&str> = NegotiatedFormat::ALL
+                    .iter()
+                    .map(|format| format.media_type())
+                    .collect();
+
+                Err(CreateJsonResponse::not_acceptable()
+                    .error_field(header::ACCEPT.as_str())
+                    .error_message(format!(
+                        "Acceptable media types: {}.",
+                        supported.join(", ")
+                    ))
+                    .send())
+            },
+        }
+    }
+}
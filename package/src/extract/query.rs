@@ -1,9 +1,11 @@
+use std::marker::PhantomData;
+
 use axum::extract::{Query as _Query, rejection::QueryRejection};
 use axum_core::extract::FromRequestParts;
 use http::{Uri, request::Parts};
 use serde::{
     Deserialize, Deserializer,
-    de::{self, DeserializeOwned},
+    de::{self, DeserializeOwned, IntoDeserializer, Visitor},
 };
 
 use crate::response::{
@@ -57,6 +59,179 @@ where
     }
 }
 
+/// Visitor of a number from a number or a numeric string,
+/// visited directly to keep 128-bit integers.
+struct NumberOrStringVisitor<T> {
+    empty_as_none: bool,
+    marker: PhantomData<T>,
+}
+
+impl<T> NumberOrStringVisitor<T> {
+    fn new(empty_as_none: bool) -> Self {
+        Self { empty_as_none, marker: PhantomData }
+    }
+}
+
+impl<'de, T> Visitor<'de> for NumberOrStringVisitor<T>
+where
+    T: Deserialize<'de> + std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    type Value = Option<T>;
+
+    fn expecting(
+        &self,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        f.write_str("a number or a numeric string")
+    }
+
+    fn visit_i64<E: de::Error>(
+        self,
+        v: i64,
+    ) -> Result<Self::Value, E> {
+        T::deserialize(v.into_deserializer()).map(Some)
+    }
+
+    fn visit_i128<E: de::Error>(
+        self,
+        v: i128,
+    ) -> Result<Self::Value, E> {
+        T::deserialize(v.into_deserializer()).map(Some)
+    }
+
+    fn visit_u64<E: de::Error>(
+        self,
+        v: u64,
+    ) -> Result<Self::Value, E> {
+        T::deserialize(v.into_deserializer()).map(Some)
+    }
+
+    fn visit_u128<E: de::Error>(
+        self,
+        v: u128,
+    ) -> Result<Self::Value, E> {
+        T::deserialize(v.into_deserializer()).map(Some)
+    }
+
+    fn visit_f64<E: de::Error>(
+        self,
+        v: f64,
+    ) -> Result<Self::Value, E> {
+        T::deserialize(v.into_deserializer()).map(Some)
+    }
+
+    fn visit_str<E: de::Error>(
+        self,
+        v: &str,
+    ) -> Result<Self::Value, E> {
+        if self.empty_as_none && v.is_empty() {
+            return Ok(None);
+        }
+
+        std::str::FromStr::from_str(v.trim()).map(Some).map_err(E::custom)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        match self.empty_as_none {
+            | true => Ok(None),
+            | false => Err(E::invalid_type(de::Unexpected::Option, &self)),
+        }
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        match self.empty_as_none {
+            | true => Ok(None),
+            | false => Err(E::invalid_type(de::Unexpected::Unit, &self)),
+        }
+    }
+
+    fn visit_some<D: Deserializer<'de>>(
+        self,
+        de: D,
+    ) -> Result<Self::Value, D::Error> {
+        de.deserialize_any(self)
+    }
+}
+
+/// Deserializes a number from either a number or a numeric string.
+///
+/// This can be used with [`Json`](crate::extract::Json),
+/// [`Query`] and [`Form`](crate::extract::Form) to accept
+/// integers sent back as strings by JavaScript clients,
+/// e.g. with [`safe_integers`](crate::response::json::JsonResponseConfig::safe_integers).
+///
+/// 128-bit integers out of the 64-bit range are kept
+/// when they are sent as strings.
+///
+/// ## Example
+///
+/// ```no_run
+/// use serde::Deserialize;
+/// use jder_axum::extract::{
+///     Json,
+///     query::number_or_string,
+/// };
+///
+/// #[derive(Deserialize)]
+/// struct RequestBody {
+///     #[serde(deserialize_with = "number_or_string")]
+///     id: u64,
+/// }
+///
+/// // {"id":"9007199254740993"} or {"id":9007199254740993}
+/// async fn route(
+///     Json(body): Json<RequestBody>,
+/// ) {
+///     // id = 9007199254740993
+/// }
+/// ```
+pub fn number_or_string<'de, D, T>(de: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    match de.deserialize_any(NumberOrStringVisitor::<T>::new(false))? {
+        | Some(n) => Ok(n),
+        | None => Err(de::Error::custom("expected a number")),
+    }
+}
+
+/// Deserializes an optional number from either a number or a numeric string,
+/// treating `null` and empty strings as `None`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use serde::Deserialize;
+/// use jder_axum::extract::query::{
+///     Query,
+///     option_number_or_string,
+/// };
+///
+/// #[derive(Deserialize)]
+/// struct QueryParams {
+///     #[serde(default, deserialize_with = "option_number_or_string")]
+///     after: Option<u64>,
+/// }
+///
+/// // /products?after=9007199254740993
+/// async fn route(
+///     Query(query): Query<QueryParams>,
+/// ) {
+///     // after = Some(9007199254740993)
+/// }
+/// ```
+pub fn option_number_or_string<'de, D, T>(de: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    de.deserialize_any(NumberOrStringVisitor::<T>::new(true))
+}
+
 /// Extractor for deserializing query strings into a specified type.
 ///
 /// Can be used with [`empty_as_none`] to treat empty query parameters as `None`.
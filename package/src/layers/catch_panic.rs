@@ -0,0 +1,332 @@
+use std::{
+    any::Any,
+    cell::RefCell,
+    future::Future,
+    panic::{AssertUnwindSafe, catch_unwind},
+    pin::Pin,
+    sync::{Arc, Once},
+    task::{Context, Poll},
+};
+
+use http::{Request, StatusCode};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::{
+    Response as Res,
+    json::{
+        CreateJsonResponse, JsonFailureResponseFunctions, JsonResponseErrorCode,
+    },
+};
+
+type PanicFn = dyn Fn(&CaughtPanic) + Send + Sync;
+
+type PanicPayload = Box<dyn Any + Send + 'static>;
+
+/// Hook for capturing panic locations, installed once.
+static PANIC_HOOK: Once = Once::new();
+
+thread_local! {
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Install a panic hook that records the location of panics
+/// on the current thread before calling the previous hook.
+///
+/// The hook is process-wide and replaces the current hook,
+/// so it stops recording if another hook is set later.
+fn install_panic_hook() {
+    PANIC_HOOK.call_once(|| {
+        let prev = std::panic::take_hook();
+
+        std::panic::set_hook(Box::new(move |info| {
+            let location: Option<String> =
+                info.location().map(|location| location.to_string());
+
+            PANIC_LOCATION.with(|cell| *cell.borrow_mut() = location);
+
+            prev(info);
+        }));
+    });
+}
+
+/// Clear the location of a previous panic on the current thread,
+/// e.g. a panic not caught by the layer,
+/// so it is not reported for the next panic.
+fn clear_panic_location() {
+    if PANIC_HOOK.is_completed() {
+        PANIC_LOCATION.with(|cell| cell.borrow_mut().take());
+    }
+}
+
+/// Panic caught by the [`CatchPanic`] layer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaughtPanic {
+    /// Message of the panic,
+    /// if the payload is a string.
+    pub message: Option<String>,
+    /// Location of the panic, e.g. `src/main.rs:10:5`,
+    /// only captured if [`capture_location`](CatchPanic::capture_location)
+    /// is set.
+    pub location: Option<String>,
+}
+
+impl CaughtPanic {
+    /// Create from a panic payload and the captured location.
+    fn from_payload(
+        payload: &PanicPayload,
+        capture_location: bool,
+    ) -> Self {
+        let message: Option<String> =
+            match payload.downcast_ref::<&'static str>() {
+                | Some(message) => Some(message.to_string()),
+                | None => payload.downcast_ref::<String>().cloned(),
+            };
+
+        let location: Option<String> =
+            PANIC_LOCATION.with(|cell| cell.borrow_mut().take());
+
+        Self { message, location: location.filter(|_| capture_location) }
+    }
+}
+
+/// Service that catches panics of the inner service.
+///
+/// Created by the [`CatchPanic`] layer.
+#[derive(Debug, Clone)]
+pub struct CatchPanicService<S> {
+    inner: S,
+    layer: CatchPanic,
+}
+
+impl<B, S> Service<Request<B>> for CatchPanicService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<
+        Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let layer: CatchPanic = self.layer.clone();
+
+        clear_panic_location();
+
+        // panics before the future is created
+        let mut fut: Pin<Box<S::Future>> =
+            match catch_unwind(AssertUnwindSafe(|| self.inner.call(req))) {
+                | Ok(fut) => Box::pin(fut),
+                | Err(payload) => {
+                    let res: Res = layer.create_response(payload);
+
+                    return Box::pin(async move { Ok(res) });
+                },
+            };
+
+        Box::pin(async move {
+            let result: Result<Result<Res, S::Error>, PanicPayload> =
+                std::future::poll_fn(|cx| {
+                    clear_panic_location();
+
+                    match catch_unwind(AssertUnwindSafe(|| {
+                        fut.as_mut().poll(cx)
+                    })) {
+                        | Ok(Poll::Ready(result)) => Poll::Ready(Ok(result)),
+                        | Ok(Poll::Pending) => Poll::Pending,
+                        | Err(payload) => Poll::Ready(Err(payload)),
+                    }
+                })
+                .await;
+
+            match result {
+                | Ok(result) => result,
+                | Err(payload) => Ok(layer.create_response(payload)),
+            }
+        })
+    }
+}
+
+/// Layer that catches panics in routes,
+/// and sends a failure response with status code `500`
+/// and error code `server` instead of dropping the connection.
+///
+/// Only unwinding panics can be caught,
+/// i.e. panics are not caught with `panic = "abort"`.
+///
+/// Panic locations are only captured with
+/// [`capture_location`](Self::capture_location),
+/// which installs a process-wide panic hook.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::layers::CatchPanic;
+///
+/// let app: Router = Router::new().layer(
+///     CatchPanic::new()
+///         .debug(cfg!(debug_assertions))
+///         .capture_location()
+///         .on_panic(|panic| {
+///             eprintln!("panic at {:?}: {:?}", panic.location, panic.message);
+///         }),
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct CatchPanic {
+    debug: bool,
+    capture_location: bool,
+    on_panic: Option<Arc<PanicFn>>,
+}
+
+impl CatchPanic {
+    /// Create a new `CatchPanic` layer.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::CatchPanic;
+    ///
+    /// CatchPanic::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Include the panic message as the error message of the response.
+    ///
+    /// Panic messages may contain sensitive information,
+    /// so it should be enabled for development only.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::CatchPanic;
+    ///
+    /// CatchPanic::new().debug(cfg!(debug_assertions));
+    /// ```
+    pub fn debug(
+        mut self,
+        debug: bool,
+    ) -> Self {
+        self.debug = debug;
+
+        self
+    }
+
+    /// Capture the location of caught panics
+    /// in [`CaughtPanic::location`].
+    ///
+    /// ## Panic hook
+    ///
+    /// The location is only available to panic hooks,
+    /// so a process-wide hook is installed with [`std::panic::set_hook`]
+    /// when this is called for the first time.
+    /// The hook records the location for the current thread
+    /// and then calls the previous hook,
+    /// so the default panic message is still printed.
+    ///
+    /// Hooks replace each other, so set any panic hook of the application
+    /// before calling this, or the location will be `None`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::CatchPanic;
+    ///
+    /// CatchPanic::new().capture_location().on_panic(|panic| {
+    ///     eprintln!("panic at {:?}", panic.location);
+    /// });
+    /// ```
+    pub fn capture_location(mut self) -> Self {
+        install_panic_hook();
+
+        self.capture_location = true;
+
+        self
+    }
+
+    /// Set a callback called with the caught panic,
+    /// e.g. for logging the panic.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::CatchPanic;
+    ///
+    /// CatchPanic::new().on_panic(|panic| {
+    ///     eprintln!("panic: {:?}", panic.message);
+    /// });
+    /// ```
+    pub fn on_panic<F>(
+        mut self,
+        on_panic: F,
+    ) -> Self
+    where
+        F: Fn(&CaughtPanic) + Send + Sync + 'static,
+    {
+        self.on_panic = Some(Arc::new(on_panic));
+
+        self
+    }
+
+    /// Create the response of a caught panic.
+    fn create_response(
+        &self,
+        payload: PanicPayload,
+    ) -> Res {
+        let panic: CaughtPanic =
+            CaughtPanic::from_payload(&payload, self.capture_location);
+
+        if let Some(on_panic) = &self.on_panic {
+            on_panic(&panic);
+        }
+
+        let res: JsonFailureResponseFunctions<()> =
+            CreateJsonResponse::failure()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .error_code(JsonResponseErrorCode::Server.as_str());
+
+        match (self.debug, panic.message) {
+            | (true, Some(message)) => res.error_message(message).send(),
+            | _ => res.send(),
+        }
+    }
+}
+
+impl std::fmt::Debug for CatchPanic {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_struct("CatchPanic")
+            .field("debug", &self.debug)
+            .field("capture_location", &self.capture_location)
+            .field("on_panic", &self.on_panic.is_some())
+            .finish()
+    }
+}
+
+impl<S> Layer<S> for CatchPanic {
+    type Service = CatchPanicService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        CatchPanicService { inner, layer: self.clone() }
+    }
+}
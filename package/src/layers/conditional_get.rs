@@ -0,0 +1,246 @@
+use std::{task::Context, time::SystemTime};
+
+use axum_core::body::Body;
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use bytes::Bytes;
+use headers::{
+    ETag, HeaderMapExt as _, IfModifiedSince, IfNoneMatch, LastModified,
+};
+use http::{HeaderValue, Method, Request, StatusCode, header};
+use http_body::Body as _;
+use http_body_util::BodyExt as _;
+use sha2::{Digest, Sha256};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::{Response as Res, json::create_json_server_error};
+
+/// Default maximum body size in bytes for generating `ETag`.
+pub const CONDITIONAL_GET_MAX_SIZE_DEFAULT: u64 = 1024 * 1024;
+
+/// Headers kept in a `304 Not Modified` response.
+const NOT_MODIFIED_HEADERS: [header::HeaderName; 7] = [
+    header::CACHE_CONTROL,
+    header::CONTENT_LOCATION,
+    header::DATE,
+    header::ETAG,
+    header::EXPIRES,
+    header::LAST_MODIFIED,
+    header::VARY,
+];
+
+/// Create a strong `ETag` from the SHA-256 hash of the body.
+fn create_strong_etag(body: &Bytes) -> HeaderValue {
+    let hash: String = URL_SAFE_NO_PAD.encode(Sha256::digest(body));
+
+    // base64url characters are always valid in header values
+    HeaderValue::from_str(&format!("\"{}\"", hash)).unwrap()
+}
+
+/// Check if the response is not modified
+/// with the conditional request headers.
+fn is_not_modified(
+    res: &Res,
+    if_none_match: Option<IfNoneMatch>,
+    if_modified_since: Option<IfModifiedSince>,
+) -> bool {
+    // `If-Modified-Since` is ignored when `If-None-Match` is present
+    if let Some(if_none_match) = if_none_match {
+        return match res.headers().typed_get::<ETag>() {
+            | Some(etag) => !if_none_match.precondition_passes(&etag),
+            | None => false,
+        };
+    }
+
+    match (if_modified_since, res.headers().typed_get::<LastModified>()) {
+        | (Some(since), Some(last_modified)) => {
+            !since.is_modified(SystemTime::from(last_modified))
+        },
+        | _ => false,
+    }
+}
+
+/// Create a `304 Not Modified` response from a response.
+fn create_not_modified(res: Res) -> Res {
+    let mut not_modified: Res = Res::new(Body::empty());
+
+    *not_modified.status_mut() = StatusCode::NOT_MODIFIED;
+    *not_modified.version_mut() = res.version();
+
+    for name in NOT_MODIFIED_HEADERS {
+        for value in res.headers().get_all(&name) {
+            not_modified.headers_mut().append(name.clone(), value.clone());
+        }
+    }
+
+    not_modified
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ConditionalGetService<S> {
+    inner: S,
+    max_size: u64,
+}
+
+impl<B, S> Service<Request<B>> for ConditionalGetService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let method: Method = req.method().clone();
+        let if_none_match: Option<IfNoneMatch> = req.headers().typed_get();
+        let if_modified_since: Option<IfModifiedSince> =
+            req.headers().typed_get();
+
+        let max_size: u64 = self.max_size;
+        let fut: S::Future = self.inner.call(req);
+
+        Box::pin(async move {
+            let res: Res = fut.await?;
+
+            if (method != Method::GET && method != Method::HEAD)
+                || res.status() != StatusCode::OK
+            {
+                return Ok(res);
+            }
+
+            // generate ETag for bodies with known size only,
+            // so streaming bodies are not buffered
+            let generate_etag: bool = !res.headers().contains_key(header::ETAG)
+                && res
+                    .body()
+                    .size_hint()
+                    .exact()
+                    .is_some_and(|size| size <= max_size);
+
+            let res: Res = match generate_etag {
+                | true => {
+                    let (mut parts, body) = res.into_parts();
+
+                    let body: Bytes = match body.collect().await {
+                        | Ok(collected) => collected.to_bytes(),
+                        // the error is not sent to the client
+                        | Err(_) => return Ok(create_json_server_error()),
+                    };
+
+                    parts
+                        .headers
+                        .insert(header::ETAG, create_strong_etag(&body));
+
+                    Res::from_parts(parts, Body::from(body))
+                },
+                | false => res,
+            };
+
+            match is_not_modified(&res, if_none_match, if_modified_since) {
+                | true => Ok(create_not_modified(res)),
+                | false => Ok(res),
+            }
+        })
+    }
+}
+
+/// Layer for conditional `GET` and `HEAD` requests.
+///
+/// A strong `ETag` is generated from the SHA-256 hash of the body
+/// for `200` responses without an `ETag`,
+/// if the size of the body is known and within the limit.
+/// `HEAD` requests get the same `ETag` as `GET` requests,
+/// as the body is generated by the `GET` route before it is removed.
+/// Weak `ETag` and `Last-Modified` can be set in routes with
+/// [`etag`](crate::response::json::JsonSuccessResponseFunctions::etag)
+/// and [`last_modified`](crate::response::json::JsonSuccessResponseFunctions::last_modified).
+///
+/// A `304 Not Modified` response is sent if `If-None-Match` matches the `ETag`,
+/// or `If-Modified-Since` is not earlier than `Last-Modified`.
+///
+/// The layer must be added after
+/// [`JsonResponseConfig`](crate::response::json::JsonResponseConfig),
+/// so it sits outside and hashes the configured body.
+/// If it sits inside, JSON bodies are hashed in compact form
+/// and the config is not applied to them.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::layers::ConditionalGet;
+///
+/// let app: Router = Router::new().layer(ConditionalGet::new());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ConditionalGet {
+    max_size: u64,
+}
+
+impl ConditionalGet {
+    /// Create a new `ConditionalGet` layer
+    /// with [CONDITIONAL_GET_MAX_SIZE_DEFAULT].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::ConditionalGet;
+    ///
+    /// ConditionalGet::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { max_size: CONDITIONAL_GET_MAX_SIZE_DEFAULT }
+    }
+
+    /// Set the maximum body size in bytes for generating `ETag`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::ConditionalGet;
+    ///
+    /// ConditionalGet::new().max_size(64 * 1024); // 64KB
+    /// ```
+    pub fn max_size(
+        mut self,
+        max_size: u64,
+    ) -> Self {
+        self.max_size = max_size;
+
+        self
+    }
+}
+
+impl Default for ConditionalGet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for ConditionalGet {
+    type Service = ConditionalGetService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        ConditionalGetService { inner, max_size: self.max_size }
+    }
+}
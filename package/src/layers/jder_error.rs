@@ -0,0 +1,317 @@
+use std::{collections::HashSet, sync::Arc, task::Context};
+
+use axum_core::body::Body;
+use bytes::Bytes;
+use http::{HeaderValue, Request, StatusCode, header};
+use http_body_util::{BodyExt as _, Limited};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::{
+    error::JderError,
+    layers::{REPLACED_HEADERS, is_content_encoded},
+    response::{
+        Response as Res,
+        json::{CreateJsonResponse, JsonResponseErrorCode},
+    },
+};
+
+/// Maximum body size in bytes read as the message of an error.
+pub const JDER_ERROR_MESSAGE_LIMIT: usize = 16 * 1024;
+
+type JderErrorMapFn =
+    dyn Fn(StatusCode, Option<String>) -> JderError + Send + Sync;
+
+/// Map an error response into an error with the default mapping.
+fn map_error_default(
+    status: StatusCode,
+    message: Option<String>,
+) -> JderError {
+    let error: JderError =
+        JderError::new(JsonResponseErrorCode::from_status(status))
+            .with_status(status);
+
+    match message {
+        | Some(message) => error.with_message(message),
+        | None => error,
+    }
+}
+
+/// Check if a content type without parameters is JSON,
+/// e.g. `application/json` or `application/problem+json`.
+fn is_json_mime(mime: &str) -> bool {
+    mime == "application/json" || mime.ends_with("+json")
+}
+
+/// Get the content type without parameters in lowercase.
+fn get_mime(content_type: Option<&HeaderValue>) -> Option<String> {
+    content_type
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|mime| mime.trim().to_ascii_lowercase())
+}
+
+/// Read the body of an error response as its message,
+/// returns `None` if it is empty or cannot be read.
+async fn read_message(body: Body) -> Option<String> {
+    match Limited::new(body, JDER_ERROR_MESSAGE_LIMIT).collect().await {
+        | Ok(collected) => {
+            let bytes: Bytes = collected.to_bytes();
+            let text: String =
+                String::from_utf8_lossy(&bytes).trim().to_string();
+
+            (!text.is_empty()).then_some(text)
+        },
+        | Err(_) => None,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct JderErrorService<S> {
+    inner: S,
+    layer: JderErrorLayer,
+}
+
+impl<B, S> Service<Request<B>> for JderErrorService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let layer: JderErrorLayer = self.layer.clone();
+        let fut: S::Future = self.inner.call(req);
+
+        Box::pin(async move {
+            let res: Res = fut.await?;
+
+            if !layer.should_rewrite(&res) {
+                return Ok(res);
+            }
+
+            let (parts, body) = res.into_parts();
+
+            // message is omitted if the body is encoded or cannot be read
+            let message: Option<String> =
+                match is_content_encoded(&parts.headers) {
+                    | true => None,
+                    | false => read_message(body).await,
+                };
+
+            let error: JderError = (layer.map)(parts.status, message);
+
+            let mut res: Res = CreateJsonResponse::failure()
+                .status(error.status)
+                .version(parts.version)
+                .error(error.to_json_response_error())
+                .send();
+
+            // keep headers such as `Allow` and `Retry-After`
+            for (name, value) in parts.headers.iter() {
+                if !REPLACED_HEADERS.contains(name) {
+                    res.headers_mut().append(name.clone(), value.clone());
+                }
+            }
+
+            Ok(res)
+        })
+    }
+}
+
+/// Layer that rewrites non-JSON error responses into JSON failure responses,
+/// e.g. `404` and `405` of routers,
+/// `413` of `DefaultBodyLimit` and errors of other layers.
+///
+/// The error code is mapped from the status code with
+/// [`JsonResponseErrorCode::from_status`],
+/// and the original body is kept as the message
+/// unless it is encoded, e.g. compressed with `Content-Encoding`.
+/// Representation headers of the original body
+/// such as `Content-Encoding` and `ETag` are dropped.
+/// Responses with a JSON content type,
+/// e.g. `application/json` or `application/problem+json`,
+/// are kept as is.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::layers::JderErrorLayer;
+///
+/// let app: Router = Router::new().layer(JderErrorLayer::new());
+/// ```
+#[derive(Clone)]
+pub struct JderErrorLayer {
+    allowed_statuses: HashSet<StatusCode>,
+    allowed_content_types: HashSet<String>,
+    map: Arc<JderErrorMapFn>,
+}
+
+impl JderErrorLayer {
+    /// Create a new `JderErrorLayer` layer.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::JderErrorLayer;
+    ///
+    /// JderErrorLayer::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            allowed_statuses: HashSet::new(),
+            allowed_content_types: HashSet::new(),
+            map: Arc::new(map_error_default),
+        }
+    }
+
+    /// Keep responses with a status code as is.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::layers::JderErrorLayer;
+    ///
+    /// JderErrorLayer::new()
+    ///     .allow_status(StatusCode::UNAUTHORIZED)
+    ///     .allow_status(StatusCode::SERVICE_UNAVAILABLE);
+    /// ```
+    pub fn allow_status<S: Into<StatusCode>>(
+        mut self,
+        status: S,
+    ) -> Self {
+        self.allowed_statuses.insert(status.into());
+
+        self
+    }
+
+    /// Keep responses with a content type as is,
+    /// compared without parameters such as `charset`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::JderErrorLayer;
+    ///
+    /// JderErrorLayer::new()
+    ///     .allow_content_type("text/html")
+    ///     .allow_content_type("application/grpc");
+    /// ```
+    pub fn allow_content_type<S: Into<String>>(
+        mut self,
+        content_type: S,
+    ) -> Self {
+        self.allowed_content_types
+            .insert(content_type.into().trim().to_ascii_lowercase());
+
+        self
+    }
+
+    /// Set a custom function for mapping an error response
+    /// into an error,
+    /// with the status code and the original body if any.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     layers::JderErrorLayer,
+    ///     response::json::JsonResponseErrorCode,
+    /// };
+    ///
+    /// JderErrorLayer::new().map(|status, message| {
+    ///     match status {
+    ///         // hide details of server errors
+    ///         | status if status.is_server_error() => {
+    ///             JderError::new(JsonResponseErrorCode::Server)
+    ///         },
+    ///         | status => JderError::new(JsonResponseErrorCode::from_status(status))
+    ///             .with_status(status)
+    ///             .with_message(message.unwrap_or_default()),
+    ///     }
+    /// });
+    /// ```
+    pub fn map<F>(
+        mut self,
+        map: F,
+    ) -> Self
+    where
+        F: Fn(StatusCode, Option<String>) -> JderError + Send + Sync + 'static,
+    {
+        self.map = Arc::new(map);
+
+        self
+    }
+
+    /// Check if a response should be rewritten.
+    fn should_rewrite(
+        &self,
+        res: &Res,
+    ) -> bool {
+        let status: StatusCode = res.status();
+
+        if !(status.is_client_error() || status.is_server_error())
+            || self.allowed_statuses.contains(&status)
+        {
+            return false;
+        }
+
+        match get_mime(res.headers().get(header::CONTENT_TYPE)) {
+            | Some(mime) => {
+                !is_json_mime(&mime)
+                    && !self.allowed_content_types.contains(&mime)
+            },
+            | None => true,
+        }
+    }
+}
+
+impl Default for JderErrorLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for JderErrorLayer {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_struct("JderErrorLayer")
+            .field("allowed_statuses", &self.allowed_statuses)
+            .field("allowed_content_types", &self.allowed_content_types)
+            .finish()
+    }
+}
+
+impl<S> Layer<S> for JderErrorLayer {
+    type Service = JderErrorService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        JderErrorService { inner, layer: self.clone() }
+    }
+}
@@ -0,0 +1,113 @@
+use std::task::Context;
+
+use axum_core::body::Body;
+use http::Request;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::{
+    Response as Res,
+    json::{
+        JsonResponseConfig,
+        config::{JsonBody, JsonDeferred, JsonOutput},
+        create_json_server_error,
+    },
+};
+
+/// Apply the JSON output of a request to a response
+/// if its body is created by the response builders.
+///
+/// The kind of the body is removed from the response,
+/// so the innermost config is applied when layers are nested.
+fn apply_json_output(
+    mut res: Res,
+    output: JsonOutput,
+) -> Res {
+    let kind: JsonBody = match res.extensions_mut().remove::<JsonBody>() {
+        | Some(kind) => kind,
+        | None => return res,
+    };
+
+    let deferred: JsonDeferred = match kind {
+        | JsonBody::Stream(config) => {
+            // the body is not polled yet
+            let _ = config.set(output);
+
+            return res;
+        },
+        | JsonBody::Buffered(deferred) => deferred,
+    };
+
+    match deferred.serialize(&output) {
+        | Some(Ok(body)) => {
+            *res.body_mut() = Body::from(body);
+
+            res
+        },
+        | Some(Err(err)) => {
+            tracing::error!(error = %err, "JSON response body failed");
+
+            create_json_server_error()
+        },
+        // the body is polled already
+        | None => res,
+    }
+}
+
+/// Service that serializes the JSON bodies of responses
+/// with the output of the request.
+///
+/// Created by the [`JsonResponseConfig`] layer.
+#[derive(Debug, Clone)]
+pub struct JsonResponseConfigService<S> {
+    inner: S,
+    config: JsonResponseConfig,
+}
+
+impl<B, S> Service<Request<B>> for JsonResponseConfigService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let output: JsonOutput = self.config.to_output(req.uri().query());
+        let fut: S::Future = self.inner.call(req);
+
+        Box::pin(async move {
+            let res: Res = fut.await?;
+
+            Ok(apply_json_output(res, output))
+        })
+    }
+}
+
+impl<S> Layer<S> for JsonResponseConfig {
+    type Service = JsonResponseConfigService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        JsonResponseConfigService { inner, config: self.clone() }
+    }
+}
@@ -1,3 +1,28 @@
+/// Catch panic layer,
+/// available with `catch_panic` feature.
+#[cfg(feature = "catch_panic")]
+pub mod catch_panic;
+
+/// Conditional GET layer,
+/// available with `conditional_get` feature.
+#[cfg(feature = "conditional_get")]
+pub mod conditional_get;
+
+/// JDER error layer,
+/// available with `jder_error` feature.
+#[cfg(feature = "jder_error")]
+pub mod jder_error;
+
+/// JSON response config layer,
+/// available with `json_config` feature.
+#[cfg(feature = "json_config")]
+pub mod json_config;
+
+/// Problem details layer,
+/// available with `problem_details` feature.
+#[cfg(feature = "problem_details")]
+pub mod problem_details;
+
 /// Request body limit layer,
 /// available with `request_body_limit` feature.
 #[cfg(feature = "request_body_limit")]
@@ -8,6 +33,48 @@ pub mod request_body_limit;
 #[cfg(feature = "request_time_limit")]
 pub mod request_time_limit;
 
+/// Representation headers of an original response
+/// not copied into a rewritten response,
+/// as they describe the original body.
+#[cfg(any(feature = "jder_error", feature = "problem_details"))]
+pub(crate) const REPLACED_HEADERS: [http::HeaderName; 10] = [
+    http::header::CONTENT_TYPE,
+    http::header::CONTENT_LENGTH,
+    http::header::TRANSFER_ENCODING,
+    http::header::CONTENT_ENCODING,
+    http::header::CONTENT_LANGUAGE,
+    http::header::CONTENT_LOCATION,
+    http::header::CONTENT_RANGE,
+    http::header::CONTENT_DISPOSITION,
+    http::header::ETAG,
+    http::header::LAST_MODIFIED,
+];
+
+/// Check if the body of a response is encoded,
+/// e.g. compressed with `gzip`, which cannot be read as is.
+#[cfg(any(feature = "jder_error", feature = "problem_details"))]
+pub(crate) fn is_content_encoded(headers: &http::HeaderMap) -> bool {
+    headers
+        .get_all(http::header::CONTENT_ENCODING)
+        .iter()
+        .any(|value| !value.as_bytes().eq_ignore_ascii_case(b"identity"))
+}
+
+#[cfg(feature = "catch_panic")]
+pub use crate::layers::catch_panic::CatchPanic;
+
+#[cfg(feature = "conditional_get")]
+pub use crate::layers::conditional_get::ConditionalGet;
+
+#[cfg(feature = "jder_error")]
+pub use crate::layers::jder_error::JderErrorLayer;
+
+#[cfg(feature = "json_config")]
+pub use crate::response::json::JsonResponseConfig;
+
+#[cfg(feature = "problem_details")]
+pub use crate::layers::problem_details::ProblemDetailsLayer;
+
 #[cfg(feature = "request_body_limit")]
 pub use crate::layers::request_body_limit::RequestBodyLimit;
 
@@ -0,0 +1,242 @@
+use std::task::Context;
+
+use axum::extract::OriginalUri;
+use axum_core::{body::Body, response::IntoResponse};
+use bytes::Bytes;
+use http::{HeaderMap, HeaderValue, Request, StatusCode, header};
+use http_body_util::BodyExt as _;
+use serde_json::Value;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::{
+    layers::{REPLACED_HEADERS, is_content_encoded},
+    response::{
+        Response as Res,
+        json::{JsonResponse, PROBLEM_JSON_CONTENT_TYPE, ProblemDetails},
+    },
+};
+
+/// Maximum body size in bytes of a failure response
+/// converted into problem details.
+pub const PROBLEM_DETAILS_BODY_LIMIT: usize = 64 * 1024;
+
+/// Get the quality of a media type in the `Accept` header,
+/// or `0` if the media type is not listed.
+fn get_accept_quality(
+    headers: &HeaderMap,
+    mime: &str,
+) -> f32 {
+    headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|range| {
+            let mut params = range.split(';');
+
+            let name: &str = params.next()?.trim();
+
+            if !name.eq_ignore_ascii_case(mime) {
+                return None;
+            }
+
+            let quality: f32 = params
+                .filter_map(|param| param.split_once('='))
+                .find(|(key, _)| key.trim().eq_ignore_ascii_case("q"))
+                .map(|(_, value)| value.trim().parse::<f32>().unwrap_or(0.0))
+                .unwrap_or(1.0);
+
+            Some(quality)
+        })
+        .fold(0.0, f32::max)
+}
+
+/// Check if a request prefers problem details,
+/// i.e. `application/problem+json` is accepted
+/// at least as much as `application/json`.
+fn prefers_problem_json(headers: &HeaderMap) -> bool {
+    let problem: f32 = get_accept_quality(headers, PROBLEM_JSON_CONTENT_TYPE);
+
+    problem > 0.0 && problem >= get_accept_quality(headers, "application/json")
+}
+
+/// Check if a response is a JDER failure response.
+fn is_jder_failure(res: &Res) -> bool {
+    let status: StatusCode = res.status();
+
+    if !(status.is_client_error() || status.is_server_error()) {
+        return false;
+    }
+
+    res.headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .is_some_and(|mime| {
+            mime.trim().eq_ignore_ascii_case("application/json")
+        })
+}
+
+/// Convert a JDER failure response into problem details.
+///
+/// The response is kept as is if the body is encoded, too large
+/// or is not a failure JSON response.
+async fn convert_response(
+    res: Res,
+    instance: String,
+) -> Res {
+    if is_content_encoded(res.headers()) {
+        return res;
+    }
+
+    let within_limit: bool = http_body::Body::size_hint(res.body())
+        .upper()
+        .is_some_and(|upper| upper <= PROBLEM_DETAILS_BODY_LIMIT as u64);
+
+    if !within_limit {
+        return res;
+    }
+
+    let (parts, body) = res.into_parts();
+
+    let bytes: Bytes = match body.collect().await {
+        | Ok(collected) => collected.to_bytes(),
+        | Err(_) => {
+            return ProblemDetails::new(parts.status)
+                .with_instance(instance)
+                .into_response();
+        },
+    };
+
+    let jder: JsonResponse<Value> = match serde_json::from_slice(&bytes) {
+        | Ok(jder) => jder,
+        | Err(_) => return Res::from_parts(parts, Body::from(bytes)),
+    };
+
+    if jder.success {
+        return Res::from_parts(parts, Body::from(bytes));
+    }
+
+    let mut res: Res = ProblemDetails::from_json_response(parts.status, &jder)
+        .with_instance(instance)
+        .into_response();
+
+    *res.version_mut() = parts.version;
+
+    // keep headers such as `Allow` and `Retry-After`
+    for (name, value) in parts.headers.iter() {
+        if !REPLACED_HEADERS.contains(name) {
+            res.headers_mut().append(name.clone(), value.clone());
+        }
+    }
+
+    res
+}
+
+#[derive(Debug, Clone)]
+pub struct ProblemDetailsService<S> {
+    inner: S,
+}
+
+impl<B, S> Service<Request<B>> for ProblemDetailsService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let prefers_problem: bool = prefers_problem_json(req.headers());
+        // path before nesting if the layer is applied to a nested router
+        let instance: String = match req.extensions().get::<OriginalUri>() {
+            | Some(uri) => uri.path().to_string(),
+            | None => req.uri().path().to_string(),
+        };
+        let fut: S::Future = self.inner.call(req);
+
+        Box::pin(async move {
+            let res: Res = fut.await?;
+
+            if !is_jder_failure(&res) {
+                return Ok(res);
+            }
+
+            let mut res: Res = match prefers_problem {
+                | true => convert_response(res, instance).await,
+                | false => res,
+            };
+
+            // failure responses depend on the `Accept` header
+            res.headers_mut()
+                .append(header::VARY, HeaderValue::from_static("accept"));
+
+            Ok(res)
+        })
+    }
+}
+
+/// Layer that converts failure JSON responses into
+/// [problem details](ProblemDetails) with
+/// `application/problem+json` content type,
+/// for clients that prefer `application/problem+json`
+/// over `application/json` in the `Accept` header.
+///
+/// Other clients receive failure JSON responses as usual.
+/// The path of the request is used as `instance`,
+/// and the error code, field and errors are kept as extension members.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::layers::ProblemDetailsLayer;
+///
+/// let app: Router = Router::new().layer(ProblemDetailsLayer::new());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ProblemDetailsLayer;
+
+impl ProblemDetailsLayer {
+    /// Create a new `ProblemDetailsLayer` layer.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::ProblemDetailsLayer;
+    ///
+    /// ProblemDetailsLayer::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for ProblemDetailsLayer {
+    type Service = ProblemDetailsService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        ProblemDetailsService { inner }
+    }
+}
@@ -66,9 +66,33 @@
 //!         .body("hi")
 //! }
 //! ```
+//!
+//! For returning errors with `?` in routes, use
+//! [`JderError`](error::JderError):
+//!
+//! ```no_run
+//! use jder_axum::{
+//!     error::JderError,
+//!     response::json::JsonResponseErrorCode,
+//! };
+//!
+//! async fn route() -> Result<(), JderError> {
+//!     Err(JderError::new(JsonResponseErrorCode::NotFound))
+//! }
+//! ```
+
+pub mod error;
 
 pub mod extract;
 
 pub mod layers;
 
 pub mod response;
+
+pub mod routing;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use axum_core::response::IntoResponse;
+    pub use http::StatusCode;
+}
@@ -0,0 +1,17 @@
+pub use cookie::{Cookie, Expiration, SameSite};
+
+/// Create a removal cookie,
+/// with path `/` if the path is not set.
+pub(crate) fn create_removal_cookie<C: Into<Cookie<'static>>>(
+    cookie: C
+) -> Cookie<'static> {
+    let mut cookie: Cookie<'static> = cookie.into();
+
+    if cookie.path().is_none() {
+        cookie.set_path("/");
+    }
+
+    cookie.make_removal();
+
+    cookie
+}
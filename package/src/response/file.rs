@@ -0,0 +1,941 @@
+use std::{
+    collections::VecDeque,
+    io::SeekFrom,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use axum_core::body::Body;
+use bytes::{Bytes, BytesMut};
+use futures_util::stream;
+use headers::{ETag, HeaderMapExt as _, IfRange, LastModified, Range};
+use http::{
+    Error as HTTPError, HeaderMap, HeaderName, HeaderValue, StatusCode,
+    Version, header,
+};
+use tokio::{
+    fs::File,
+    io::{AsyncRead, AsyncReadExt as _, AsyncSeekExt as _},
+};
+
+use crate::{
+    error::JderError,
+    response::{
+        Response,
+        header::append_header,
+        json::{CreateJsonResponse, JsonResponseError, JsonResponseErrorCode},
+    },
+};
+
+#[cfg(feature = "cookie")]
+use crate::response::cookie::{Cookie, create_removal_cookie};
+
+/// Maximum size of a chunk read from a file.
+const FILE_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Maximum number of ranges after coalescing,
+/// the whole file is sent if a request has more.
+const FILE_RANGES_MAX: usize = 16;
+
+/// Counter for creating unique boundaries of multipart bodies.
+static BOUNDARY_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Disposition of a file response in the `Content-Disposition` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FileDisposition {
+    /// Display the file in the browser.
+    #[default]
+    Inline,
+    /// Download the file.
+    Attachment,
+}
+
+impl FileDisposition {
+    /// Get the disposition as a string.
+    pub fn as_str(&self) -> &str {
+        match self {
+            | Self::Inline => "inline",
+            | Self::Attachment => "attachment",
+        }
+    }
+}
+
+/// Source of a file response.
+enum FileSource {
+    Path(PathBuf),
+    Reader(Pin<Box<dyn AsyncRead + Send>>),
+}
+
+/// Opened source of a file response.
+enum FileReader {
+    File(File),
+    Reader(Pin<Box<dyn AsyncRead + Send>>),
+}
+
+impl FileReader {
+    /// Read a chunk of at most `max` bytes,
+    /// an empty chunk means the end of the source.
+    async fn read_chunk(
+        &mut self,
+        max: usize,
+    ) -> std::io::Result<Bytes> {
+        let mut buf: BytesMut = BytesMut::with_capacity(max);
+
+        match self {
+            | Self::File(file) => {
+                file.take(max as u64).read_buf(&mut buf).await?;
+            },
+            | Self::Reader(reader) => {
+                reader.take(max as u64).read_buf(&mut buf).await?;
+            },
+        }
+
+        Ok(buf.freeze())
+    }
+
+    /// Seek to a position, only available for files.
+    async fn seek(
+        &mut self,
+        pos: u64,
+    ) -> std::io::Result<()> {
+        match self {
+            | Self::File(file) => {
+                file.seek(SeekFrom::Start(pos)).await.map(|_| ())
+            },
+            | Self::Reader(_) => Err(std::io::ErrorKind::Unsupported.into()),
+        }
+    }
+}
+
+/// Segment of a file body.
+enum FileSegment {
+    /// Bytes written as is, e.g. headers of multipart bodies.
+    Bytes(Bytes),
+    /// Range of the file with the start and length.
+    Range(u64, u64),
+    /// Rest of the source until the end.
+    Rest,
+}
+
+/// Internal state of a file body.
+struct FileBodyState {
+    reader: FileReader,
+    segments: VecDeque<FileSegment>,
+    position: u64,
+}
+
+/// Create the body of a file response from segments.
+fn create_file_body(
+    reader: FileReader,
+    segments: VecDeque<FileSegment>,
+) -> Body {
+    let initial: FileBodyState =
+        FileBodyState { reader, segments, position: 0 };
+
+    Body::from_stream(stream::unfold(initial, |mut state| async move {
+        loop {
+            let chunk: std::io::Result<Bytes> = match state.segments.front_mut()
+            {
+                | None => return None,
+                | Some(FileSegment::Bytes(bytes)) => {
+                    let bytes: Bytes = std::mem::take(bytes);
+
+                    state.segments.pop_front();
+
+                    Ok(bytes)
+                },
+                | Some(FileSegment::Range(_, 0)) => {
+                    state.segments.pop_front();
+
+                    continue;
+                },
+                | Some(FileSegment::Range(start, len)) => {
+                    let (start, len) = (*start, *len);
+
+                    if state.position != start {
+                        if let Err(err) = state.reader.seek(start).await {
+                            state.segments.clear();
+
+                            return Some((Err(err), state));
+                        }
+                    }
+
+                    let max: usize = len.min(FILE_CHUNK_SIZE as u64) as usize;
+
+                    match state.reader.read_chunk(max).await {
+                        | Ok(chunk) if chunk.is_empty() => {
+                            Err(std::io::ErrorKind::UnexpectedEof.into())
+                        },
+                        | Ok(chunk) => {
+                            let read: u64 = chunk.len() as u64;
+
+                            state.position = start + read;
+                            state.segments[0] =
+                                FileSegment::Range(start + read, len - read);
+
+                            Ok(chunk)
+                        },
+                        | Err(err) => Err(err),
+                    }
+                },
+                | Some(FileSegment::Rest) => {
+                    match state.reader.read_chunk(FILE_CHUNK_SIZE).await {
+                        | Ok(chunk) if chunk.is_empty() => {
+                            state.segments.pop_front();
+
+                            continue;
+                        },
+                        | result => result,
+                    }
+                },
+            };
+
+            // stop the body after an error
+            if chunk.is_err() {
+                state.segments.clear();
+            }
+
+            return Some((chunk, state));
+        }
+    }))
+}
+
+/// Check if a byte is an `attr-char` of RFC 5987.
+fn is_attr_char(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || b"!#$&+-.^_`|~".contains(&byte)
+}
+
+/// Create a `Content-Disposition` header value of RFC 6266,
+/// with an ASCII `filename` fallback and
+/// a UTF-8 `filename*` if the name is not ASCII only.
+fn create_content_disposition(
+    disposition: FileDisposition,
+    filename: &str,
+) -> Result<HeaderValue, JsonResponseError> {
+    let fallback: String = filename
+        .chars()
+        .map(|c| match c.is_ascii() && !c.is_ascii_control() {
+            | true if c != '"' && c != '\\' => c,
+            | _ => '_',
+        })
+        .collect();
+
+    let mut value: String =
+        format!("{}; filename=\"{}\"", disposition.as_str(), fallback);
+
+    if fallback != filename {
+        value.push_str("; filename*=UTF-8''");
+
+        for byte in filename.bytes() {
+            match is_attr_char(byte) {
+                | true => value.push(byte as char),
+                | false => value.push_str(&format!("%{:02X}", byte)),
+            }
+        }
+    }
+
+    HeaderValue::from_str(&value).map_err(|err| JsonResponseError {
+        code: JsonResponseErrorCode::Server.as_str().to_string(),
+        field: Some(header::CONTENT_DISPOSITION.as_str().to_string()),
+        message: Some(err.to_string()),
+    })
+}
+
+/// Create a unique boundary for multipart bodies.
+fn create_boundary() -> String {
+    let nanos: u128 = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+
+    let count: u64 = BOUNDARY_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+    format!("jder_{:x}_{:x}", nanos, count)
+}
+
+/// Resolve the satisfiable ranges of a `Range` header
+/// as inclusive start and end,
+/// sorted with overlapping and adjacent ranges coalesced.
+fn resolve_ranges(
+    range: &Range,
+    len: u64,
+) -> Vec<(u64, u64)> {
+    use std::ops::Bound;
+
+    let mut ranges: Vec<(u64, u64)> = range
+        .satisfiable_ranges(len)
+        .filter_map(|(start, end)| {
+            let start: u64 = match start {
+                | Bound::Included(start) => start,
+                | Bound::Excluded(start) => start.checked_add(1)?,
+                | Bound::Unbounded => 0,
+            };
+
+            let end: u64 = match end {
+                | Bound::Included(end) => end.min(len.checked_sub(1)?),
+                | Bound::Excluded(end) => end.checked_sub(1)?.min(len - 1),
+                | Bound::Unbounded => len.checked_sub(1)?,
+            };
+
+            (start <= end && start < len).then_some((start, end))
+        })
+        .collect();
+
+    ranges.sort_unstable();
+
+    let mut coalesced: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+
+    for (start, end) in ranges {
+        match coalesced.last_mut() {
+            | Some((_, prev_end)) if start <= prev_end.saturating_add(1) => {
+                *prev_end = (*prev_end).max(end);
+            },
+            | _ => coalesced.push((start, end)),
+        }
+    }
+
+    coalesced
+}
+
+/// Create a header value generated for a file response,
+/// or returns an error naming the header if it is invalid.
+fn create_file_header_value(
+    name: &HeaderName,
+    value: String,
+) -> Result<HeaderValue, JderError> {
+    HeaderValue::try_from(value).map_err(|err| {
+        JderError::new(JsonResponseErrorCode::Server)
+            .with_field(name.as_str())
+            .with_message(err.to_string())
+    })
+}
+
+/// Create the error of a file that cannot be opened.
+fn create_file_error(err: std::io::Error) -> JderError {
+    match err.kind() {
+        | std::io::ErrorKind::NotFound => {
+            JderError::new(JsonResponseErrorCode::NotFound)
+                .with_message("File not found.")
+                .with_source(err)
+        },
+        | _ => JderError::from(err),
+    }
+}
+
+/// Internal state.
+struct FileResponseState {
+    version: Version,
+    header_map: HeaderMap,
+    header_map_error: Option<JsonResponseError>,
+    source: FileSource,
+    filename: Option<String>,
+    disposition: FileDisposition,
+    range: Option<Range>,
+    if_range: Option<IfRange>,
+}
+
+/// Functions for creating file response,
+/// available with `file` feature.
+///
+/// Created with [`CreateResponse::file`](crate::response::CreateResponse::file)
+/// or [`CreateResponse::attachment`](crate::response::CreateResponse::attachment).
+pub struct FileResponseFunctions {
+    state: FileResponseState,
+}
+
+impl std::fmt::Debug for FileResponseFunctions {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        let path: Option<&Path> = match &self.state.source {
+            | FileSource::Path(path) => Some(path),
+            | FileSource::Reader(_) => None,
+        };
+
+        f.debug_struct("FileResponseFunctions")
+            .field("version", &self.state.version)
+            .field("header_map", &self.state.header_map)
+            .field("path", &path)
+            .field("filename", &self.state.filename)
+            .field("disposition", &self.state.disposition)
+            .field("range", &self.state.range)
+            .finish()
+    }
+}
+
+impl FileResponseFunctions {
+    /// Create the functions from a source.
+    fn new(
+        source: FileSource,
+        filename: Option<String>,
+        disposition: FileDisposition,
+    ) -> Self {
+        Self {
+            state: FileResponseState {
+                version: Version::HTTP_11,
+                header_map: HeaderMap::new(),
+                header_map_error: None,
+                source,
+                filename,
+                disposition,
+                range: None,
+                if_range: None,
+            },
+        }
+    }
+
+    /// Set the HTTP version of the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::Version;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateResponse::file("assets/logo.png")
+    ///         .version(Version::HTTP_2)
+    ///         .send()
+    ///         .await
+    /// }
+    /// ```
+    pub fn version<V: Into<Version>>(
+        mut self,
+        version: V,
+    ) -> Self {
+        self.state.version = version.into();
+
+        self
+    }
+
+    /// Set a header for the response.
+    ///
+    /// The `Content-Type` header is guessed from the filename
+    /// if it is not set.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::header;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateResponse::file("assets/logo.png")
+    ///         .header(header::CACHE_CONTROL, "max-age=3600")
+    ///         .send()
+    ///         .await
+    /// }
+    /// ```
+    pub fn header<K, V>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        if let Err(err) = append_header(&mut self.state.header_map, key, value)
+        {
+            // keep the first error
+            if self.state.header_map_error.is_none() {
+                self.state.header_map_error = Some(err);
+            }
+        }
+
+        self
+    }
+
+    /// Set multiple headers for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::header;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateResponse::file("assets/logo.png")
+    ///         .headers([
+    ///             (header::CACHE_CONTROL, "max-age=3600"),
+    ///             (header::ACCESS_CONTROL_ALLOW_ORIGIN, "*"),
+    ///         ])
+    ///         .send()
+    ///         .await
+    /// }
+    /// ```
+    pub fn headers<K, V>(
+        mut self,
+        headers: impl IntoIterator<Item = (K, V)>,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        for (key, value) in headers {
+            self = self.header(key, value);
+        }
+
+        self
+    }
+
+    /// Set a cookie for the response,
+    /// available with `cookie` feature.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse,
+    ///     cookie::Cookie,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateResponse::file("reports/2024.pdf")
+    ///         .cookie(Cookie::new("downloaded", "true"))
+    ///         .send()
+    ///         .await
+    /// }
+    /// ```
+    #[cfg(feature = "cookie")]
+    pub fn cookie<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        self.header(header::SET_COOKIE, cookie.into().encoded().to_string())
+    }
+
+    /// Remove a cookie on the client,
+    /// available with `cookie` feature.
+    ///
+    /// The path of the cookie is set to `/` if it is not set,
+    /// the path and domain should match the cookie to be removed.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateResponse::file("reports/2024.pdf")
+    ///         .remove_cookie("download_token")
+    ///         .send()
+    ///         .await
+    /// }
+    /// ```
+    #[cfg(feature = "cookie")]
+    pub fn remove_cookie<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        self.cookie(create_removal_cookie(cookie))
+    }
+
+    /// Set the filename in the `Content-Disposition` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateResponse::file("reports/2024.pdf")
+    ///         .filename("年報.pdf")
+    ///         .send()
+    ///         .await
+    /// }
+    /// ```
+    pub fn filename<S: Into<String>>(
+        mut self,
+        filename: S,
+    ) -> Self {
+        self.state.filename = Some(filename.into());
+
+        self
+    }
+
+    /// Set the disposition in the `Content-Disposition` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse,
+    ///     file::FileDisposition,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateResponse::file("reports/2024.pdf")
+    ///         .disposition(FileDisposition::Attachment)
+    ///         .send()
+    ///         .await
+    /// }
+    /// ```
+    pub fn disposition(
+        mut self,
+        disposition: FileDisposition,
+    ) -> Self {
+        self.state.disposition = disposition;
+
+        self
+    }
+
+    /// Answer the `Range` and `If-Range` headers of a request
+    /// with a `206 Partial Content` response,
+    /// available for [`file`](crate::response::CreateResponse::file) only.
+    ///
+    /// Multiple ranges are sent as a `multipart/byteranges` body,
+    /// with overlapping and adjacent ranges coalesced.
+    /// If more than 16 ranges remain, the whole file is sent
+    /// with status code `200`.
+    /// If no range can be satisfied, a failure response
+    /// with status code `416` and error code `range_not_satisfiable`
+    /// will be sent.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::HeaderMap;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse
+    /// };
+    ///
+    /// async fn route(headers: HeaderMap) -> Response {
+    ///     CreateResponse::file("videos/intro.mp4")
+    ///         .range(&headers)
+    ///         .send()
+    ///         .await
+    /// }
+    /// ```
+    pub fn range(
+        mut self,
+        request_headers: &HeaderMap,
+    ) -> Self {
+        // invalid headers are ignored as in RFC 9110
+        self.state.range = request_headers.typed_get();
+        self.state.if_range = request_headers.typed_get();
+
+        self
+    }
+
+    /// Send the file.
+    ///
+    /// If the file cannot be opened or any header is invalid,
+    /// a JSON failure response will be sent instead,
+    /// e.g. status code `404` and error code `not_found`
+    /// for a missing file.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateResponse::file("assets/logo.png").send().await
+    /// }
+    /// ```
+    pub async fn send(self) -> Response {
+        match self.try_send().await {
+            | Ok(res) => res,
+            | Err(err) => CreateJsonResponse::failure()
+                .status(err.status)
+                .error(err.to_json_response_error())
+                .send(),
+        }
+    }
+
+    /// Send the file,
+    /// or returns an error if the file cannot be opened
+    /// or any header is invalid.
+    ///
+    /// A failure response with status code `416` is not an error,
+    /// as it carries the `Content-Range` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     response::{
+    ///         Response,
+    ///         CreateResponse
+    ///     },
+    /// };
+    ///
+    /// async fn route() -> Result<Response, JderError> {
+    ///     CreateResponse::file("assets/logo.png").try_send().await
+    /// }
+    /// ```
+    pub async fn try_send(self) -> Result<Response, JderError> {
+        let mut state: FileResponseState = self.state;
+
+        if let Some(err) = state.header_map_error {
+            return Err(JderError::from_json_response_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                err,
+            ));
+        }
+
+        let (reader, len) = match state.source {
+            | FileSource::Path(path) => {
+                let file: File =
+                    File::open(&path).await.map_err(create_file_error)?;
+
+                let metadata: std::fs::Metadata =
+                    file.metadata().await.map_err(create_file_error)?;
+
+                if metadata.is_dir() {
+                    return Err(create_file_error(
+                        std::io::ErrorKind::NotFound.into(),
+                    ));
+                }
+
+                if state.filename.is_none() {
+                    state.filename = path
+                        .file_name()
+                        .map(|name| name.to_string_lossy().into_owned());
+                }
+
+                if let (false, Ok(modified)) = (
+                    state.header_map.contains_key(header::LAST_MODIFIED),
+                    metadata.modified(),
+                ) {
+                    state.header_map.typed_insert(LastModified::from(modified));
+                }
+
+                (FileReader::File(file), Some(metadata.len()))
+            },
+            | FileSource::Reader(reader) => (FileReader::Reader(reader), None),
+        };
+
+        // set content type and disposition
+        let content_type: HeaderValue =
+            match state.header_map.get(header::CONTENT_TYPE) {
+                | Some(content_type) => content_type.clone(),
+                | None => {
+                    let mime: String = state
+                        .filename
+                        .as_ref()
+                        .and_then(|name| mime_guess::from_path(name).first())
+                        .unwrap_or(mime_guess::mime::APPLICATION_OCTET_STREAM)
+                        .to_string();
+
+                    HeaderValue::from_str(&mime).map_err(|err| {
+                        JderError::new(JsonResponseErrorCode::Server)
+                            .with_message(err.to_string())
+                    })?
+                },
+            };
+
+        if let Some(filename) = &state.filename {
+            if !state.header_map.contains_key(header::CONTENT_DISPOSITION) {
+                let value: HeaderValue =
+                    create_content_disposition(state.disposition, filename)
+                        .map_err(|err| {
+                            JderError::from_json_response_error(
+                                StatusCode::INTERNAL_SERVER_ERROR,
+                                err,
+                            )
+                        })?;
+
+                state.header_map.insert(header::CONTENT_DISPOSITION, value);
+            }
+        }
+
+        let mut header_map: HeaderMap = state.header_map;
+        let mut status: StatusCode = StatusCode::OK;
+        let mut segments: VecDeque<FileSegment> = VecDeque::new();
+
+        match len {
+            | Some(len) => {
+                header_map.insert(
+                    header::ACCEPT_RANGES,
+                    HeaderValue::from_static("bytes"),
+                );
+
+                // ranges are ignored if the file is modified
+                let range: Option<Range> = match state.if_range {
+                    | Some(if_range) => {
+                        let etag: Option<ETag> = header_map.typed_get();
+                        let last_modified: Option<LastModified> =
+                            header_map.typed_get();
+
+                        match if_range
+                            .is_modified(etag.as_ref(), last_modified.as_ref())
+                        {
+                            | true => None,
+                            | false => state.range,
+                        }
+                    },
+                    | None => state.range,
+                };
+
+                // too many ranges are ignored instead of
+                // sending many small parts
+                let ranges: Option<Vec<(u64, u64)>> = range
+                    .map(|range| resolve_ranges(&range, len))
+                    .filter(|ranges| ranges.len() <= FILE_RANGES_MAX);
+
+                match ranges.as_deref() {
+                    | None => {
+                        header_map.insert(header::CONTENT_TYPE, content_type);
+                        header_map.insert(header::CONTENT_LENGTH, len.into());
+
+                        segments.push_back(FileSegment::Range(0, len));
+                    },
+                    | Some([]) => {
+                        return Ok(CreateJsonResponse::failure()
+                            .code(JsonResponseErrorCode::RangeNotSatisfiable)
+                            .version(state.version)
+                            .header(
+                                header::CONTENT_RANGE,
+                                format!("bytes */{}", len),
+                            )
+                            .error_message("Range not satisfiable.")
+                            .send());
+                    },
+                    | Some([(start, end)]) => {
+                        status = StatusCode::PARTIAL_CONTENT;
+
+                        header_map.insert(header::CONTENT_TYPE, content_type);
+                        header_map.insert(
+                            header::CONTENT_LENGTH,
+                            (end - start + 1).into(),
+                        );
+                        header_map.insert(
+                            header::CONTENT_RANGE,
+                            create_file_header_value(
+                                &header::CONTENT_RANGE,
+                                format!("bytes {}-{}/{}", start, end, len),
+                            )?,
+                        );
+
+                        segments.push_back(FileSegment::Range(
+                            *start,
+                            end - start + 1,
+                        ));
+                    },
+                    | Some(ranges) => {
+                        status = StatusCode::PARTIAL_CONTENT;
+
+                        let boundary: String = create_boundary();
+                        let mut content_length: u64 = 0;
+
+                        for (start, end) in ranges {
+                            let part: Bytes = Bytes::from(format!(
+                                "\r\n--{}\r\n{}: {}\r\n{}: bytes {}-{}/{}\r\n\r\n",
+                                boundary,
+                                header::CONTENT_TYPE,
+                                content_type.to_str().unwrap_or_default(),
+                                header::CONTENT_RANGE,
+                                start,
+                                end,
+                                len,
+                            ));
+
+                            content_length +=
+                                part.len() as u64 + end - start + 1;
+
+                            segments.push_back(FileSegment::Bytes(part));
+                            segments.push_back(FileSegment::Range(
+                                *start,
+                                end - start + 1,
+                            ));
+                        }
+
+                        let end: Bytes =
+                            Bytes::from(format!("\r\n--{}--\r\n", boundary));
+
+                        content_length += end.len() as u64;
+
+                        segments.push_back(FileSegment::Bytes(end));
+
+                        header_map.insert(
+                            header::CONTENT_TYPE,
+                            create_file_header_value(
+                                &header::CONTENT_TYPE,
+                                format!(
+                                    "multipart/byteranges; boundary={}",
+                                    boundary
+                                ),
+                            )?,
+                        );
+                        header_map.insert(
+                            header::CONTENT_LENGTH,
+                            content_length.into(),
+                        );
+                    },
+                }
+            },
+            | None => {
+                header_map.insert(header::CONTENT_TYPE, content_type);
+
+                segments.push_back(FileSegment::Rest);
+            },
+        }
+
+        let mut res: Response =
+            Response::new(create_file_body(reader, segments));
+
+        *res.status_mut() = status;
+        *res.version_mut() = state.version;
+        *res.headers_mut() = header_map;
+
+        Ok(res)
+    }
+}
+
+/// Create the functions for a file from a path.
+pub(crate) fn create_file_response<P: AsRef<Path>>(
+    path: P
+) -> FileResponseFunctions {
+    FileResponseFunctions::new(
+        FileSource::Path(path.as_ref().to_path_buf()),
+        None,
+        FileDisposition::Inline,
+    )
+}
+
+/// Create the functions for an attachment from a reader.
+pub(crate) fn create_attachment_response<S, R>(
+    filename: S,
+    reader: R,
+) -> FileResponseFunctions
+where
+    S: Into<String>,
+    R: AsyncRead + Send + 'static,
+{
+    FileResponseFunctions::new(
+        FileSource::Reader(Box::pin(reader)),
+        Some(filename.into()),
+        FileDisposition::Attachment,
+    )
+}
@@ -1,4 +1,6 @@
-use http::{Error as HTTPError, HeaderName, HeaderValue};
+use http::{Error as HTTPError, HeaderMap, HeaderName, HeaderValue};
+
+use crate::response::json::{JsonResponseError, JsonResponseErrorCode};
 
 /// Convert a key into a header name.
 ///
@@ -87,3 +89,138 @@ where
 
     Ok((k, v))
 }
+
+/// Append a key-value pair to a header map.
+///
+/// Returns an error naming the header and the conversion failure
+/// if the key, the value or the header map is invalid.
+pub(crate) fn append_header<K, V>(
+    header_map: &mut HeaderMap,
+    key: K,
+    value: V,
+) -> Result<(), JsonResponseError>
+where
+    HeaderName: TryFrom<K>,
+    <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+    HeaderValue: TryFrom<V>,
+    <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+{
+    let key: HeaderName = match get_header_name_from_key(key) {
+        | Ok(k) => k,
+        | Err(err) => {
+            // the key is moved by the conversion, so describe its type
+            return Err(JsonResponseError {
+                code: JsonResponseErrorCode::Server.to_string(),
+                field: Some("header_map".to_string()),
+                message: Some(format!(
+                    "Invalid header name from `{}`: {}",
+                    std::any::type_name::<K>(),
+                    err
+                )),
+            });
+        },
+    };
+
+    let value: HeaderValue = match get_header_value_from_value(value) {
+        | Ok(v) => v,
+        | Err(err) => {
+            return Err(JsonResponseError {
+                code: JsonResponseErrorCode::Server.to_string(),
+                field: Some(key.to_string()),
+                message: Some(format!(
+                    "Invalid value for header `{}`: {}",
+                    key, err
+                )),
+            });
+        },
+    };
+
+    if let Err(err) = header_map.try_append(&key, value) {
+        return Err(JsonResponseError {
+            code: JsonResponseErrorCode::Server.to_string(),
+            field: Some(key.to_string()),
+            message: Some(format!(
+                "Failed to append header `{}`: {}",
+                key, err
+            )),
+        });
+    }
+
+    Ok(())
+}
+
+/// Create a weak `ETag` value from a version.
+///
+/// Returns an error if the version contains characters
+/// not allowed in an entity tag.
+#[cfg(feature = "conditional_get")]
+pub(crate) fn create_weak_etag<V: std::fmt::Display>(
+    version: V
+) -> Result<HeaderValue, JsonResponseError> {
+    let version: String = version.to_string();
+
+    // etagc = %x21 / %x23-7E / obs-text
+    let valid: bool = version
+        .bytes()
+        .all(|b| b == 0x21 || (0x23..=0x7E).contains(&b) || b >= 0x80);
+
+    match valid {
+        | true => HeaderValue::from_str(&format!("W/\"{}\"", version)).ok(),
+        | false => None,
+    }
+    .ok_or_else(|| JsonResponseError {
+        code: JsonResponseErrorCode::Server.to_string(),
+        field: Some(http::header::ETAG.to_string()),
+        message: Some(format!("Invalid version for ETag: {}", version)),
+    })
+}
+
+/// Check if a location is an absolute `http` / `https` URL
+/// or an absolute path, which is allowed in the `Location` header.
+///
+/// Relative paths such as `users/1` or `../users`,
+/// and characters not allowed in URIs are rejected.
+pub(crate) fn is_valid_location(location: &str) -> bool {
+    let allowed: bool = !location.is_empty()
+        && location.bytes().all(|b| b.is_ascii_graphic() && b != b'\\');
+
+    if !allowed {
+        return false;
+    }
+
+    if location.starts_with('/') {
+        // network path reference requires a host, e.g. `//example.com`
+        return match location.strip_prefix("//") {
+            | Some(rest) => !rest.is_empty() && !rest.starts_with('/'),
+            | None => true,
+        };
+    }
+
+    let rest: Option<&str> =
+        ["http://", "https://"].iter().find_map(|prefix| {
+            location
+                .get(..prefix.len())
+                .filter(|scheme| scheme.eq_ignore_ascii_case(prefix))
+                .map(|_| &location[prefix.len()..])
+        });
+
+    rest.is_some_and(|rest| !rest.is_empty() && !rest.starts_with('/'))
+}
+
+/// Create a `Location` value from a location.
+///
+/// Returns an error if the location is not valid,
+/// see [`is_valid_location`].
+pub(crate) fn create_location(
+    location: &str
+) -> Result<HeaderValue, JsonResponseError> {
+    match is_valid_location(location) {
+        | true => HeaderValue::from_str(location).ok(),
+        | false => None,
+    }
+    .ok_or_else(|| JsonResponseError {
+        code: JsonResponseErrorCode::Server.to_string(),
+        field: Some(http::header::LOCATION.to_string()),
+        message: Some(format!("Invalid location: {}", location)),
+    })
+}
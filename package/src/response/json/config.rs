@@ -0,0 +1,625 @@
+#[cfg(any(feature = "stream", feature = "json_config"))]
+use std::sync::{Arc, OnceLock};
+
+use axum_core::response::Response;
+use bytes::Bytes;
+use serde::{Serialize, ser::SerializeStruct};
+use serde_json::{Serializer, ser::PrettyFormatter};
+
+use crate::response::json::{
+    JsonResponse, JsonResponseError, integers::SafeIntegers,
+};
+
+#[cfg(feature = "json_config")]
+use std::{
+    pin::Pin,
+    sync::{Mutex, MutexGuard, PoisonError},
+    task::{Context, Poll},
+};
+
+#[cfg(feature = "json_config")]
+use axum_core::body::Body;
+#[cfg(feature = "json_config")]
+use http_body::{Frame, SizeHint};
+#[cfg(feature = "json_config")]
+use serde_json::ser::Formatter;
+
+/// Initial capacity of the buffer for serializing JSON bodies.
+const JSON_BODY_CAPACITY: usize = 128;
+
+#[cfg(feature = "json_config")]
+type JsonFormatFn = dyn Fn(
+        &dyn erased_serde::Serialize,
+        &mut Vec<u8>,
+    ) -> Result<(), serde_json::Error>
+    + Send
+    + Sync;
+
+#[cfg(feature = "json_config")]
+type JsonSerializeFn =
+    dyn FnOnce(&JsonOutput) -> Result<Bytes, serde_json::Error> + Send;
+
+/// When to pretty print JSON bodies,
+/// available with `json_config` feature.
+#[cfg(feature = "json_config")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum JsonPretty {
+    /// Never pretty print, unless requested with the `pretty` query.
+    #[default]
+    Never,
+    /// Pretty print in debug builds only.
+    Debug,
+    /// Always pretty print.
+    Always,
+}
+
+/// Configuration for JSON output,
+/// applied to the bodies of JSON response builders
+/// and [`Json`](crate::extract::Json) responses
+/// passing through the layer.
+///
+/// Bodies are serialized once by the layer with the configuration,
+/// so responses created on any thread or task are configured.
+/// Bodies of responses not passing through the layer
+/// are serialized compactly when they are first read,
+/// and aborted if they cannot be serialized.
+///
+/// The configuration is set on a router as a layer,
+/// available with `json_config` feature.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::response::json::{JsonPretty, JsonResponseConfig};
+///
+/// let app: Router = Router::new().layer(
+///     JsonResponseConfig::new()
+///         .pretty(JsonPretty::Debug)
+///         .pretty_query(true)
+///         .omit_null(true),
+/// );
+/// ```
+#[cfg(feature = "json_config")]
+#[derive(Clone, Default)]
+pub struct JsonResponseConfig {
+    pub(crate) pretty: JsonPretty,
+    pub(crate) pretty_query: bool,
+    pub(crate) omit_null: bool,
+    pub(crate) safe_integers: bool,
+    pub(crate) formatter: Option<Arc<JsonFormatFn>>,
+}
+
+#[cfg(feature = "json_config")]
+impl JsonResponseConfig {
+    /// Create a new configuration with compact output.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// JsonResponseConfig::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set when to pretty print JSON bodies.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::{JsonPretty, JsonResponseConfig};
+    ///
+    /// JsonResponseConfig::new().pretty(JsonPretty::Debug);
+    /// ```
+    pub fn pretty(
+        mut self,
+        pretty: JsonPretty,
+    ) -> Self {
+        self.pretty = pretty;
+
+        self
+    }
+
+    /// Allow pretty printing per request with the `pretty` query,
+    /// e.g. `/users?pretty`.
+    ///
+    /// The query is ignored if its value is `false` or `0`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// JsonResponseConfig::new().pretty_query(true);
+    /// ```
+    pub fn pretty_query(
+        mut self,
+        pretty_query: bool,
+    ) -> Self {
+        self.pretty_query = pretty_query;
+
+        self
+    }
+
+    /// Omit `field` and `message` of errors when they are `null`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// JsonResponseConfig::new().omit_null(true);
+    /// ```
+    pub fn omit_null(
+        mut self,
+        omit_null: bool,
+    ) -> Self {
+        self.omit_null = omit_null;
+
+        self
+    }
+
+    /// Serialize integers out of the safe range of JavaScript
+    /// as strings in `data`,
+    /// i.e. integers greater than `2^53 - 1` or less than `-(2^53 - 1)`.
+    ///
+    /// For accepting the strings back in extractors, see
+    /// [`number_or_string`](crate::extract::query::number_or_string).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// JsonResponseConfig::new().safe_integers(true);
+    /// ```
+    pub fn safe_integers(
+        mut self,
+        safe_integers: bool,
+    ) -> Self {
+        self.safe_integers = safe_integers;
+
+        self
+    }
+
+    /// Set a custom [`Formatter`] for JSON bodies.
+    ///
+    /// Pretty printing takes precedence over the formatter
+    /// when it is enabled.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponseConfig;
+    /// use serde_json::ser::PrettyFormatter;
+    ///
+    /// JsonResponseConfig::new()
+    ///     .formatter(PrettyFormatter::with_indent(b"\t"));
+    /// ```
+    pub fn formatter<F>(
+        mut self,
+        formatter: F,
+    ) -> Self
+    where
+        F: Formatter + Clone + Send + Sync + 'static,
+    {
+        self.formatter = Some(Arc::new(move |value, buf| {
+            let mut ser: Serializer<&mut Vec<u8>, F> =
+                Serializer::with_formatter(buf, formatter.clone());
+
+            erased_serde::serialize(value, &mut ser)
+        }));
+
+        self
+    }
+
+    /// Resolve the output of a request from its query.
+    pub(crate) fn to_output(
+        &self,
+        query: Option<&str>,
+    ) -> JsonOutput {
+        let pretty: bool = match self.pretty {
+            | JsonPretty::Never => false,
+            | JsonPretty::Debug => cfg!(debug_assertions),
+            | JsonPretty::Always => true,
+        };
+
+        let pretty_query: bool = self.pretty_query
+            && query.is_some_and(|query| {
+                query.split('&').any(|pair| {
+                    let (key, value) =
+                        pair.split_once('=').unwrap_or((pair, ""));
+
+                    key == "pretty" && value != "false" && value != "0"
+                })
+            });
+
+        JsonOutput {
+            pretty: pretty || pretty_query,
+            omit_null: self.omit_null,
+            safe_integers: self.safe_integers,
+            formatter: self.formatter.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "json_config")]
+impl std::fmt::Debug for JsonResponseConfig {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_struct("JsonResponseConfig")
+            .field("pretty", &self.pretty)
+            .field("pretty_query", &self.pretty_query)
+            .field("omit_null", &self.omit_null)
+            .field("safe_integers", &self.safe_integers)
+            .field("formatter", &self.formatter.is_some())
+            .finish()
+    }
+}
+
+/// Resolved JSON output of a request.
+#[derive(Clone, Default)]
+pub(crate) struct JsonOutput {
+    pub(crate) pretty: bool,
+    pub(crate) omit_null: bool,
+    pub(crate) safe_integers: bool,
+    #[cfg(feature = "json_config")]
+    pub(crate) formatter: Option<Arc<JsonFormatFn>>,
+}
+
+impl JsonOutput {
+    /// Serialize a value into JSON bytes
+    /// with pretty printing or the formatter.
+    pub(crate) fn to_bytes<T: Serialize>(
+        &self,
+        value: &T,
+    ) -> Result<Bytes, serde_json::Error> {
+        if self.pretty {
+            let mut buf: Vec<u8> = Vec::with_capacity(JSON_BODY_CAPACITY);
+
+            let mut ser: Serializer<&mut Vec<u8>, PrettyFormatter> =
+                Serializer::pretty(&mut buf);
+
+            value.serialize(&mut ser)?;
+
+            return Ok(Bytes::from(buf));
+        }
+
+        #[cfg(feature = "json_config")]
+        if let Some(formatter) = &self.formatter {
+            let mut buf: Vec<u8> = Vec::with_capacity(JSON_BODY_CAPACITY);
+
+            formatter(value, &mut buf)?;
+
+            return Ok(Bytes::from(buf));
+        }
+
+        to_json_bytes(value)
+    }
+
+    /// Serialize data into JSON bytes,
+    /// with safe integers if enabled.
+    #[cfg_attr(
+        not(any(feature = "json", feature = "stream")),
+        allow(dead_code)
+    )]
+    pub(crate) fn data_to_bytes<T: Serialize>(
+        &self,
+        data: &T,
+    ) -> Result<Bytes, serde_json::Error> {
+        match self.safe_integers {
+            | true => self.to_bytes(&SafeIntegers(data)),
+            | false => self.to_bytes(data),
+        }
+    }
+
+    /// Serialize a JSON response into JSON bytes,
+    /// with safe integers in `data`
+    /// and `null` fields of errors omitted if enabled.
+    pub(crate) fn response_to_bytes<D: Serialize>(
+        &self,
+        res: &JsonResponse<D>,
+    ) -> Result<Bytes, serde_json::Error> {
+        match self.omit_null || self.safe_integers {
+            | true => self.to_bytes(&JsonResponseOutput { res, output: self }),
+            | false => self.to_bytes(res),
+        }
+    }
+}
+
+/// Kind of a JSON body created by the response builders,
+/// set in the extensions of the response
+/// for [`JsonResponseConfig`] to apply the output of the request.
+#[cfg(any(feature = "stream", feature = "json_config"))]
+#[derive(Clone)]
+pub(crate) enum JsonBody {
+    /// Body serialized by the layer with the output.
+    #[cfg(feature = "json_config")]
+    Buffered(JsonDeferred),
+    /// Streamed body serialized with the output
+    /// set before the first chunk is sent.
+    #[cfg_attr(
+        not(all(feature = "stream", feature = "json_config")),
+        allow(dead_code)
+    )]
+    Stream(Arc<OnceLock<JsonOutput>>),
+}
+
+#[cfg(any(feature = "stream", feature = "json_config"))]
+impl JsonBody {
+    /// Set the kind of the body in the extensions of a response.
+    #[cfg(feature = "json_config")]
+    pub(crate) fn attach(
+        self,
+        mut res: Response,
+    ) -> Response {
+        res.extensions_mut().insert(self);
+
+        res
+    }
+
+    /// Set the kind of the body in the extensions of a response,
+    /// skipped as there is no layer to apply the output.
+    #[cfg(not(feature = "json_config"))]
+    pub(crate) fn attach(
+        self,
+        res: Response,
+    ) -> Response {
+        res
+    }
+}
+
+/// Serializer of a JSON body deferred until the output is known,
+/// shared by the body and the extensions of the response.
+#[cfg(feature = "json_config")]
+#[derive(Clone)]
+pub(crate) struct JsonDeferred(Arc<Mutex<Option<Box<JsonSerializeFn>>>>);
+
+#[cfg(feature = "json_config")]
+impl JsonDeferred {
+    /// Serialize the body with the output,
+    /// returns `None` if it is serialized already.
+    pub(crate) fn serialize(
+        &self,
+        output: &JsonOutput,
+    ) -> Option<Result<Bytes, serde_json::Error>> {
+        let serialize: Box<JsonSerializeFn> = match self.0.lock() {
+            | Ok(mut serialize) => serialize.take()?,
+            | Err(_) => return None,
+        };
+
+        Some(serialize(output))
+    }
+}
+
+/// Body of a JSON response not passing through the layer,
+/// serialized compactly when it is first read
+/// or when its size is requested.
+#[cfg(feature = "json_config")]
+struct JsonDeferredBody {
+    deferred: JsonDeferred,
+    body: Mutex<Option<Result<Bytes, serde_json::Error>>>,
+}
+
+#[cfg(feature = "json_config")]
+impl JsonDeferredBody {
+    /// Serialize the body compactly if it is not serialized yet.
+    fn serialized(
+        &self
+    ) -> MutexGuard<'_, Option<Result<Bytes, serde_json::Error>>> {
+        let mut body: MutexGuard<'_, Option<Result<Bytes, serde_json::Error>>> =
+            self.body.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if body.is_none() {
+            *body = self.deferred.serialize(&JsonOutput::default());
+        }
+
+        body
+    }
+}
+
+#[cfg(feature = "json_config")]
+impl http_body::Body for JsonDeferredBody {
+    type Data = Bytes;
+    type Error = serde_json::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let frame: Option<Result<Frame<Bytes>, serde_json::Error>> =
+            self.serialized().take().map(|body| {
+                body.map(Frame::data).inspect_err(|err| {
+                    tracing::error!(error = %err, "JSON response body failed");
+                })
+            });
+
+        Poll::Ready(frame)
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        match &*self.serialized() {
+            | Some(Ok(body)) => SizeHint::with_exact(body.len() as u64),
+            | Some(Err(_)) => SizeHint::default(),
+            | None => SizeHint::with_exact(0),
+        }
+    }
+}
+
+/// Create a response with a JSON body serialized from a value.
+///
+/// The body is serialized by [`JsonResponseConfig`]
+/// with the output of the request,
+/// or compactly when it is first read outside the layer.
+#[cfg(feature = "json_config")]
+pub(crate) fn create_json_body<T, F>(
+    value: T,
+    serialize: F,
+) -> Result<Response, serde_json::Error>
+where
+    T: Send + 'static,
+    F: FnOnce(&T, &JsonOutput) -> Result<Bytes, serde_json::Error>
+        + Send
+        + 'static,
+{
+    let deferred: JsonDeferred = JsonDeferred(Arc::new(Mutex::new(Some(
+        Box::new(move |output: &JsonOutput| serialize(&value, output)),
+    ))));
+
+    let res: Response = Response::new(Body::new(JsonDeferredBody {
+        deferred: deferred.clone(),
+        body: Mutex::new(None),
+    }));
+
+    Ok(JsonBody::Buffered(deferred).attach(res))
+}
+
+/// Create a response with a JSON body serialized from a value,
+/// serialized compactly at once as there is no layer to apply the output.
+#[cfg(not(feature = "json_config"))]
+pub(crate) fn create_json_body<T, F>(
+    value: T,
+    serialize: F,
+) -> Result<Response, serde_json::Error>
+where
+    T: Send + 'static,
+    F: FnOnce(&T, &JsonOutput) -> Result<Bytes, serde_json::Error>
+        + Send
+        + 'static,
+{
+    let body: Bytes = serialize(&value, &JsonOutput::default())?;
+
+    Ok(Response::new(axum_core::body::Body::from(body)))
+}
+
+/// Error serialized with `null` fields omitted if enabled.
+struct JsonErrorOutput<'a> {
+    error: &'a JsonResponseError,
+    omit_null: bool,
+}
+
+impl Serialize for JsonErrorOutput<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if !self.omit_null {
+            return self.error.serialize(serializer);
+        }
+
+        let len: usize = 1
+            + usize::from(self.error.field.is_some())
+            + usize::from(self.error.message.is_some());
+
+        let mut state =
+            serializer.serialize_struct("JsonResponseError", len)?;
+
+        state.serialize_field("code", &self.error.code)?;
+
+        match &self.error.field {
+            | Some(field) => state.serialize_field("field", field)?,
+            | None => state.skip_field("field")?,
+        }
+
+        match &self.error.message {
+            | Some(message) => state.serialize_field("message", message)?,
+            | None => state.skip_field("message")?,
+        }
+
+        state.end()
+    }
+}
+
+/// Data serialized with safe integers if enabled.
+struct JsonDataOutput<'a, D> {
+    data: &'a D,
+    safe_integers: bool,
+}
+
+impl<D: Serialize> Serialize for JsonDataOutput<'_, D> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self.safe_integers {
+            | true => SafeIntegers(self.data).serialize(serializer),
+            | false => self.data.serialize(serializer),
+        }
+    }
+}
+
+/// JSON response serialized with the output,
+/// keeping the keys of [`JsonResponse`].
+struct JsonResponseOutput<'a, D> {
+    res: &'a JsonResponse<D>,
+    output: &'a JsonOutput,
+}
+
+impl<D: Serialize> Serialize for JsonResponseOutput<'_, D> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let res: &JsonResponse<D> = self.res;
+
+        let len: usize = 3
+            + usize::from(!res.errors.is_empty())
+            + usize::from(res.pagination.is_some());
+
+        let mut state = serializer.serialize_struct("JsonResponse", len)?;
+
+        state.serialize_field("success", &res.success)?;
+
+        state.serialize_field(
+            "data",
+            &res.data.as_ref().map(|data| JsonDataOutput {
+                data,
+                safe_integers: self.output.safe_integers,
+            }),
+        )?;
+
+        state.serialize_field(
+            "error",
+            &res.error.as_ref().map(|error| JsonErrorOutput {
+                error,
+                omit_null: self.output.omit_null,
+            }),
+        )?;
+
+        if !res.errors.is_empty() {
+            let errors: Vec<JsonErrorOutput<'_>> = res
+                .errors
+                .iter()
+                .map(|error| JsonErrorOutput {
+                    error,
+                    omit_null: self.output.omit_null,
+                })
+                .collect();
+
+            state.serialize_field("errors", &errors)?;
+        }
+
+        if let Some(pagination) = &res.pagination {
+            state.serialize_field("pagination", pagination)?;
+        }
+
+        state.end()
+    }
+}
+
+/// Serialize a value into compact JSON bytes
+/// without an intermediate `String`.
+pub(crate) fn to_json_bytes<T: Serialize + ?Sized>(
+    value: &T
+) -> Result<Bytes, serde_json::Error> {
+    let mut buf: Vec<u8> = Vec::with_capacity(JSON_BODY_CAPACITY);
+
+    serde_json::to_writer(&mut buf, value)?;
+
+    Ok(Bytes::from(buf))
+}
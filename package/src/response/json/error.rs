@@ -1,5 +1,91 @@
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+
+/// Error code that can be used in a JSON failure response.
+///
+/// Implement this trait for custom error code enums
+/// to keep the status code and error code consistent,
+/// then pass them to
+/// [`code`](crate::response::json::JsonFailureResponseFunctions::code).
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::http::StatusCode;
+/// use jder_axum::response::{
+///     Response,
+///     json::{CreateJsonResponse, ErrorCode},
+/// };
+///
+/// enum UserErrorCode {
+///     NotFound,
+///     InvalidEmail,
+/// }
+///
+/// impl ErrorCode for UserErrorCode {
+///     fn code(&self) -> &str {
+///         match self {
+///             | Self::NotFound => "user_not_found",
+///             | Self::InvalidEmail => "invalid_email",
+///         }
+///     }
+///
+///     fn status(&self) -> StatusCode {
+///         match self {
+///             | Self::NotFound => StatusCode::NOT_FOUND,
+///             | Self::InvalidEmail => StatusCode::BAD_REQUEST,
+///         }
+///     }
+///
+///     fn field(&self) -> Option<&str> {
+///         match self {
+///             | Self::NotFound => None,
+///             | Self::InvalidEmail => Some("email"),
+///         }
+///     }
+/// }
+///
+/// async fn route() -> Response {
+///     CreateJsonResponse::failure()
+///         .code(UserErrorCode::NotFound)
+///         .send()
+/// }
+/// ```
+pub trait ErrorCode {
+    /// Get the error code.
+    fn code(&self) -> &str;
+
+    /// Get the default status code of the error.
+    fn status(&self) -> StatusCode;
+
+    /// Get the field of the error, `None` by default.
+    fn field(&self) -> Option<&str> {
+        None
+    }
+
+    /// Get the message of the error, `None` by default.
+    fn message(&self) -> Option<String> {
+        None
+    }
+}
+
 /// JSON response error code.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Serialized and deserialized as the `snake_case` error code,
+/// and can be parsed back from a `&str` with [`FromStr`](std::str::FromStr).
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::JsonResponseErrorCode;
+///
+/// let code: JsonResponseErrorCode = "not_found".parse().unwrap();
+///
+/// assert_eq!(code, JsonResponseErrorCode::NotFound);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
 pub enum JsonResponseErrorCode {
     /// Error while parsing.
     Parse,
@@ -9,11 +95,68 @@ pub enum JsonResponseErrorCode {
     Timeout,
     /// Internal server error.
     Server,
+    /// Bad request.
+    BadRequest,
+    /// Authentication required.
+    Unauthorized,
+    /// Permission denied.
+    Forbidden,
+    /// Resource not found.
+    NotFound,
+    /// Method not allowed.
+    MethodNotAllowed,
+    /// No acceptable representation.
+    NotAcceptable,
+    /// Conflict with the current state.
+    Conflict,
+    /// Resource no longer available.
+    Gone,
+    /// Precondition failed.
+    PreconditionFailed,
+    /// Unsupported media type.
+    UnsupportedMediaType,
+    /// Range not satisfiable.
+    RangeNotSatisfiable,
+    /// Unprocessable entity.
+    UnprocessableEntity,
+    /// Precondition required.
+    PreconditionRequired,
+    /// Too many requests.
+    TooManyRequests,
+    /// Not implemented.
+    NotImplemented,
+    /// Service unavailable.
+    ServiceUnavailable,
     /// Unknown error.
     Unknown,
 }
 
 impl JsonResponseErrorCode {
+    /// All error codes.
+    pub const ALL: [Self; 21] = [
+        Self::Parse,
+        Self::TooLarge,
+        Self::Timeout,
+        Self::Server,
+        Self::BadRequest,
+        Self::Unauthorized,
+        Self::Forbidden,
+        Self::NotFound,
+        Self::MethodNotAllowed,
+        Self::NotAcceptable,
+        Self::Conflict,
+        Self::Gone,
+        Self::PreconditionFailed,
+        Self::UnsupportedMediaType,
+        Self::RangeNotSatisfiable,
+        Self::UnprocessableEntity,
+        Self::PreconditionRequired,
+        Self::TooManyRequests,
+        Self::NotImplemented,
+        Self::ServiceUnavailable,
+        Self::Unknown,
+    ];
+
     /// Get the error code as `&str`.
     pub fn as_str(&self) -> &str {
         match self {
@@ -21,9 +164,114 @@ impl JsonResponseErrorCode {
             | Self::TooLarge => "too_large",
             | Self::Timeout => "timeout",
             | Self::Server => "server",
+            | Self::BadRequest => "bad_request",
+            | Self::Unauthorized => "unauthorized",
+            | Self::Forbidden => "forbidden",
+            | Self::NotFound => "not_found",
+            | Self::MethodNotAllowed => "method_not_allowed",
+            | Self::NotAcceptable => "not_acceptable",
+            | Self::Conflict => "conflict",
+            | Self::Gone => "gone",
+            | Self::PreconditionFailed => "precondition_failed",
+            | Self::UnsupportedMediaType => "unsupported_media_type",
+            | Self::RangeNotSatisfiable => "range_not_satisfiable",
+            | Self::UnprocessableEntity => "unprocessable_entity",
+            | Self::PreconditionRequired => "precondition_required",
+            | Self::TooManyRequests => "too_many_requests",
+            | Self::NotImplemented => "not_implemented",
+            | Self::ServiceUnavailable => "service_unavailable",
             | Self::Unknown => "unknown",
         }
     }
+
+    /// Get the default status code of the error code.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            | Self::Parse => StatusCode::BAD_REQUEST,
+            | Self::TooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            | Self::Timeout => StatusCode::REQUEST_TIMEOUT,
+            | Self::Server => StatusCode::INTERNAL_SERVER_ERROR,
+            | Self::BadRequest => StatusCode::BAD_REQUEST,
+            | Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            | Self::Forbidden => StatusCode::FORBIDDEN,
+            | Self::NotFound => StatusCode::NOT_FOUND,
+            | Self::MethodNotAllowed => StatusCode::METHOD_NOT_ALLOWED,
+            | Self::NotAcceptable => StatusCode::NOT_ACCEPTABLE,
+            | Self::Conflict => StatusCode::CONFLICT,
+            | Self::Gone => StatusCode::GONE,
+            | Self::PreconditionFailed => StatusCode::PRECONDITION_FAILED,
+            | Self::UnsupportedMediaType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            | Self::RangeNotSatisfiable => StatusCode::RANGE_NOT_SATISFIABLE,
+            | Self::UnprocessableEntity => StatusCode::UNPROCESSABLE_ENTITY,
+            | Self::PreconditionRequired => StatusCode::PRECONDITION_REQUIRED,
+            | Self::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
+            | Self::NotImplemented => StatusCode::NOT_IMPLEMENTED,
+            | Self::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            | Self::Unknown => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    /// Get the error code of a status code.
+    ///
+    /// Other client errors are mapped to `unknown`,
+    /// and other server errors are mapped to `server`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::json::JsonResponseErrorCode;
+    ///
+    /// assert_eq!(
+    ///     JsonResponseErrorCode::from_status(StatusCode::NOT_FOUND),
+    ///     JsonResponseErrorCode::NotFound
+    /// );
+    /// ```
+    pub fn from_status(status: StatusCode) -> Self {
+        match status {
+            | StatusCode::BAD_REQUEST => Self::BadRequest,
+            | StatusCode::UNAUTHORIZED => Self::Unauthorized,
+            | StatusCode::FORBIDDEN => Self::Forbidden,
+            | StatusCode::NOT_FOUND => Self::NotFound,
+            | StatusCode::METHOD_NOT_ALLOWED => Self::MethodNotAllowed,
+            | StatusCode::NOT_ACCEPTABLE => Self::NotAcceptable,
+            | StatusCode::REQUEST_TIMEOUT => Self::Timeout,
+            | StatusCode::CONFLICT => Self::Conflict,
+            | StatusCode::GONE => Self::Gone,
+            | StatusCode::PRECONDITION_FAILED => Self::PreconditionFailed,
+            | StatusCode::PAYLOAD_TOO_LARGE => Self::TooLarge,
+            | StatusCode::UNSUPPORTED_MEDIA_TYPE => Self::UnsupportedMediaType,
+            | StatusCode::RANGE_NOT_SATISFIABLE => Self::RangeNotSatisfiable,
+            | StatusCode::UNPROCESSABLE_ENTITY => Self::UnprocessableEntity,
+            | StatusCode::PRECONDITION_REQUIRED => Self::PreconditionRequired,
+            | StatusCode::TOO_MANY_REQUESTS => Self::TooManyRequests,
+            | StatusCode::NOT_IMPLEMENTED => Self::NotImplemented,
+            | StatusCode::SERVICE_UNAVAILABLE => Self::ServiceUnavailable,
+            | StatusCode::GATEWAY_TIMEOUT => Self::Timeout,
+            | status if status.is_server_error() => Self::Server,
+            | _ => Self::Unknown,
+        }
+    }
+}
+
+impl ErrorCode for JsonResponseErrorCode {
+    fn code(&self) -> &str {
+        self.as_str()
+    }
+
+    fn status(&self) -> StatusCode {
+        JsonResponseErrorCode::status(self)
+    }
+}
+
+impl std::str::FromStr for JsonResponseErrorCode {
+    type Err = ParseJsonResponseErrorCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter().find(|code| code.as_str() == s).ok_or_else(|| {
+            ParseJsonResponseErrorCodeError { code: s.to_string() }
+        })
+    }
 }
 
 impl std::fmt::Display for JsonResponseErrorCode {
@@ -35,4 +283,21 @@ impl std::fmt::Display for JsonResponseErrorCode {
     }
 }
 
+/// Error returned when parsing an unknown [`JsonResponseErrorCode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseJsonResponseErrorCodeError {
+    code: String,
+}
+
+impl std::fmt::Display for ParseJsonResponseErrorCodeError {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "unknown error code `{}`", self.code)
+    }
+}
+
+impl std::error::Error for ParseJsonResponseErrorCodeError {}
+
 pub(crate) const FAILURE_RESPONSE_DEFAULT: &str = "{\"success\":false,\"data\":null,\"error\":{\"code\":\"server\",\"field\":null,\"message\":\"Internal server error.\"}}";
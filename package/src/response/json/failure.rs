@@ -1,11 +1,22 @@
 use http::{Error as HTTPError, HeaderName, HeaderValue, StatusCode, Version};
 use serde::Serialize;
 
-use crate::response::{
-    Response,
-    json::{
-        JsonResponseError, JsonResponseState, create_json_response_send,
-        error::JsonResponseErrorCode,
+#[cfg(feature = "cookie")]
+use http::header;
+
+#[cfg(feature = "cookie")]
+use crate::response::cookie::{Cookie, create_removal_cookie};
+
+use crate::{
+    error::JderError,
+    response::{
+        Response,
+        header::append_header,
+        json::{
+            JsonResponseError, JsonResponseState, create_json_response_send,
+            create_json_response_try_send,
+            error::{ErrorCode, JsonResponseErrorCode},
+        },
     },
 };
 
@@ -70,6 +81,10 @@ impl<D: Serialize> JsonFailureResponseFunctions<D> {
 
     /// Set a header for the response.
     ///
+    /// If the key or value is invalid,
+    /// [`send`](Self::send) will send an internal server error instead,
+    /// and [`try_send`](Self::try_send) will return the error.
+    ///
     /// For validation on key value, see
     /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
     ///
@@ -105,24 +120,13 @@ impl<D: Serialize> JsonFailureResponseFunctions<D> {
         HeaderValue: TryFrom<V>,
         <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
     {
-        let key: HeaderName = match <HeaderName as TryFrom<K>>::try_from(key) {
-            | Ok(k) => k,
-            | Err(_) => {
-                self.state.is_header_map_failed = true;
-                return self;
-            },
-        };
-
-        let value: HeaderValue =
-            match <HeaderValue as TryFrom<V>>::try_from(value) {
-                | Ok(v) => v,
-                | Err(_) => {
-                    self.state.is_header_map_failed = true;
-                    return self;
-                },
-            };
-
-        self.state.header_map.try_append(key, value).unwrap();
+        if let Err(err) = append_header(&mut self.state.header_map, key, value)
+        {
+            // keep the first error
+            if self.state.header_map_error.is_none() {
+                self.state.header_map_error = Some(err);
+            }
+        }
 
         self
     }
@@ -178,8 +182,65 @@ impl<D: Serialize> JsonFailureResponseFunctions<D> {
         self
     }
 
+    /// Set a cookie for the response,
+    /// available with `cookie` feature.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    ///     cookie::Cookie,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure()
+    ///         .cookie(Cookie::build(("session", "value")).http_only(true))
+    ///         .send()
+    /// }
+    /// ```
+    #[cfg(feature = "cookie")]
+    pub fn cookie<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        self.header(header::SET_COOKIE, cookie.into().encoded().to_string())
+    }
+
+    /// Remove a cookie on the client,
+    /// available with `cookie` feature.
+    ///
+    /// The path of the cookie is set to `/` if it is not set,
+    /// the path and domain should match the cookie to be removed.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure()
+    ///         .remove_cookie("session")
+    ///         .send()
+    /// }
+    /// ```
+    #[cfg(feature = "cookie")]
+    pub fn remove_cookie<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        self.cookie(create_removal_cookie(cookie))
+    }
+
     /// Send the response.
     ///
+    /// If any header is invalid, a JSON failure response
+    /// with status code `500` and error code `server` will be sent instead.
+    ///
     /// ## Example
     ///
     /// ```no_run
@@ -192,9 +253,40 @@ impl<D: Serialize> JsonFailureResponseFunctions<D> {
     ///     CreateJsonResponse::failure().send()
     /// }
     /// ```
-    pub fn send(self) -> Response {
+    pub fn send(self) -> Response
+    where
+        D: Send + 'static,
+    {
         create_json_response_send(self.state)
     }
+
+    /// Send the response,
+    /// or returns an error naming the header
+    /// if any header is invalid.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     response::{
+    ///         Response,
+    ///         json::CreateJsonResponse,
+    ///     },
+    /// };
+    ///
+    /// async fn route() -> Result<Response, JderError> {
+    ///     CreateJsonResponse::failure()
+    ///         .header("x-custom", "value")
+    ///         .try_send()
+    /// }
+    /// ```
+    pub fn try_send(self) -> Result<Response, JderError>
+    where
+        D: Send + 'static,
+    {
+        create_json_response_try_send(self.state)
+    }
 }
 
 impl<D: Serialize> JsonFailureResponseFunctions<D> {
@@ -232,6 +324,105 @@ impl<D: Serialize> JsonFailureResponseFunctions<D> {
         self
     }
 
+    /// Push an error to the errors of the response,
+    /// useful for reporting multiple invalid fields at once.
+    ///
+    /// The first pushed error will also be set as the `error`
+    /// of the response if it is not set.
+    /// Setting the error with [`code`](Self::code),
+    /// [`error_field`](Self::error_field) or
+    /// [`error_message`](Self::error_message) only changes `error`,
+    /// so the pushed errors are kept as is.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{
+    ///         CreateJsonResponse,
+    ///         JsonResponseError,
+    ///     },
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure()
+    ///         .push_error(JsonResponseError {
+    ///             code: "parse".to_string(),
+    ///             field: Some("title".to_string()),
+    ///             message: Some("Invalid title".to_string()),
+    ///         })
+    ///         .push_error(JsonResponseError {
+    ///             code: "parse".to_string(),
+    ///             field: Some("email".to_string()),
+    ///             message: Some("Invalid email".to_string()),
+    ///         })
+    ///         .send()
+    /// }
+    /// ```
+    pub fn push_error(
+        mut self,
+        error: JsonResponseError,
+    ) -> Self {
+        if self.state.error.is_none() {
+            self.state.error = Some(error.clone());
+        }
+
+        self.state.errors.push(error);
+
+        self
+    }
+
+    /// Set the status code, error code, error field and error message
+    /// for the response from an [`ErrorCode`].
+    ///
+    /// The error field and error message will be kept
+    /// if the error code does not provide them.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{
+    ///         CreateJsonResponse,
+    ///         JsonResponseErrorCode,
+    ///     },
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure()
+    ///         .code(JsonResponseErrorCode::Timeout)
+    ///         .send()
+    /// }
+    /// ```
+    pub fn code<C: ErrorCode>(
+        mut self,
+        code: C,
+    ) -> Self {
+        self.state.status = code.status();
+
+        self.state.error = Some(JsonResponseError {
+            code: code.code().to_string(),
+            field: match code.field() {
+                | Some(field) => Some(field.to_string()),
+                | None => match &self.state.error {
+                    | Some(error) => error.field.clone(),
+                    | None => None,
+                },
+            },
+            message: match code.message() {
+                | Some(message) => Some(message),
+                | None => match self.state.error {
+                    | Some(error) => error.message,
+                    | None => None,
+                },
+            },
+        });
+
+        self
+    }
+
     /// Set an error code for the response.
     ///
     /// ## Example
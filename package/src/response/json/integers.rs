@@ -0,0 +1,369 @@
+use serde::{
+    Serialize, Serializer,
+    ser::{
+        SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+        SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+    },
+};
+
+/// Maximum safe integer of JavaScript, i.e. `2^53 - 1`.
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// Value serialized with integers out of the safe range of JavaScript
+/// as strings, keeping the order of fields.
+pub(crate) struct SafeIntegers<'a, T: ?Sized>(pub(crate) &'a T);
+
+impl<T: Serialize + ?Sized> Serialize for SafeIntegers<'_, T> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        self.0.serialize(SafeIntegerSerializer(serializer))
+    }
+}
+
+/// Serializer that converts unsafe integers into strings.
+struct SafeIntegerSerializer<S>(S);
+
+/// Compound serializer that wraps the values with [`SafeIntegers`].
+struct SafeIntegerCompound<C>(C);
+
+macro_rules! serialize_forward {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(
+                self,
+                v: $ty,
+            ) -> Result<Self::Ok, Self::Error> {
+                self.0.$method(v)
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for SafeIntegerSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = SafeIntegerCompound<S::SerializeSeq>;
+    type SerializeTuple = SafeIntegerCompound<S::SerializeTuple>;
+    type SerializeTupleStruct = SafeIntegerCompound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = SafeIntegerCompound<S::SerializeTupleVariant>;
+    type SerializeMap = SafeIntegerCompound<S::SerializeMap>;
+    type SerializeStruct = SafeIntegerCompound<S::SerializeStruct>;
+    type SerializeStructVariant =
+        SafeIntegerCompound<S::SerializeStructVariant>;
+
+    serialize_forward!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+    );
+
+    fn serialize_i64(
+        self,
+        v: i64,
+    ) -> Result<Self::Ok, Self::Error> {
+        match v.unsigned_abs() > MAX_SAFE_INTEGER {
+            | true => self.0.collect_str(&v),
+            | false => self.0.serialize_i64(v),
+        }
+    }
+
+    fn serialize_i128(
+        self,
+        v: i128,
+    ) -> Result<Self::Ok, Self::Error> {
+        match v.unsigned_abs() > MAX_SAFE_INTEGER as u128 {
+            | true => self.0.collect_str(&v),
+            | false => self.0.serialize_i128(v),
+        }
+    }
+
+    fn serialize_u64(
+        self,
+        v: u64,
+    ) -> Result<Self::Ok, Self::Error> {
+        match v > MAX_SAFE_INTEGER {
+            | true => self.0.collect_str(&v),
+            | false => self.0.serialize_u64(v),
+        }
+    }
+
+    fn serialize_u128(
+        self,
+        v: u128,
+    ) -> Result<Self::Ok, Self::Error> {
+        match v > MAX_SAFE_INTEGER as u128 {
+            | true => self.0.collect_str(&v),
+            | false => self.0.serialize_u128(v),
+        }
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_none()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(
+        self,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_some(&SafeIntegers(value))
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_unit()
+    }
+
+    fn serialize_unit_struct(
+        self,
+        name: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_unit_struct(name)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_newtype_struct(name, &SafeIntegers(value))
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_newtype_variant(
+            name,
+            variant_index,
+            variant,
+            &SafeIntegers(value),
+        )
+    }
+
+    fn serialize_seq(
+        self,
+        len: Option<usize>,
+    ) -> Result<Self::SerializeSeq, Self::Error> {
+        self.0.serialize_seq(len).map(SafeIntegerCompound)
+    }
+
+    fn serialize_tuple(
+        self,
+        len: usize,
+    ) -> Result<Self::SerializeTuple, Self::Error> {
+        self.0.serialize_tuple(len).map(SafeIntegerCompound)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.0.serialize_tuple_struct(name, len).map(SafeIntegerCompound)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        self.0
+            .serialize_tuple_variant(name, variant_index, variant, len)
+            .map(SafeIntegerCompound)
+    }
+
+    fn serialize_map(
+        self,
+        len: Option<usize>,
+    ) -> Result<Self::SerializeMap, Self::Error> {
+        self.0.serialize_map(len).map(SafeIntegerCompound)
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.0.serialize_struct(name, len).map(SafeIntegerCompound)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.0
+            .serialize_struct_variant(name, variant_index, variant, len)
+            .map(SafeIntegerCompound)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+impl<C: SerializeSeq> SerializeSeq for SafeIntegerCompound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_element(&SafeIntegers(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: SerializeTuple> SerializeTuple for SafeIntegerCompound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_element(&SafeIntegers(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: SerializeTupleStruct> SerializeTupleStruct for SafeIntegerCompound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_field(&SafeIntegers(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: SerializeTupleVariant> SerializeTupleVariant
+    for SafeIntegerCompound<C>
+{
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_field(&SafeIntegers(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: SerializeMap> SerializeMap for SafeIntegerCompound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    // keys are written as strings in JSON already
+    fn serialize_key<T: Serialize + ?Sized>(
+        &mut self,
+        key: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_key(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_value(&SafeIntegers(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: SerializeStruct> SerializeStruct for SafeIntegerCompound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_field(key, &SafeIntegers(value))
+    }
+
+    fn skip_field(
+        &mut self,
+        key: &'static str,
+    ) -> Result<(), Self::Error> {
+        self.0.skip_field(key)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: SerializeStructVariant> SerializeStructVariant
+    for SafeIntegerCompound<C>
+{
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_field(key, &SafeIntegers(value))
+    }
+
+    fn skip_field(
+        &mut self,
+        key: &'static str,
+    ) -> Result<(), Self::Error> {
+        self.0.skip_field(key)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
@@ -0,0 +1,148 @@
+use axum_core::response::IntoResponse;
+use http::StatusCode;
+use serde::Serialize;
+
+use crate::response::{Response, json::CreateJsonResponse};
+
+/// JSON response that wraps the data in a success JSON response.
+///
+/// Can be composed with headers and status code like other responses,
+/// e.g. `(HeaderMap, Jder<T>)`.
+///
+/// ## Examples
+///
+/// An example of using `Jder` as a response:
+///
+/// ```no_run
+/// use jder_axum::response::json::Jder;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct User {
+///     id: usize,
+///     username: String,
+/// }
+///
+/// async fn route() -> Jder<User> {
+///     Jder::created(User {
+///         id: 1,
+///         username: "Name".to_string(),
+///     })
+/// }
+/// ```
+///
+/// An example of using `Jder` with headers:
+///
+/// ```no_run
+/// use axum::http::header;
+/// use jder_axum::response::json::Jder;
+///
+/// async fn route() -> ([(header::HeaderName, &'static str); 1], Jder<String>) {
+///     (
+///         [(header::CACHE_CONTROL, "no-cache")],
+///         Jder::ok("Name".to_string()),
+///     )
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Jder<T> {
+    status: StatusCode,
+    data: T,
+}
+
+impl<T> Jder<T> {
+    /// Create a success JSON response with status code `200`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::Jder;
+    ///
+    /// async fn route() -> Jder<String> {
+    ///     Jder::ok("Name".to_string())
+    /// }
+    /// ```
+    pub fn ok(data: T) -> Self {
+        Self { status: StatusCode::OK, data }
+    }
+
+    /// Create a success JSON response with status code `201`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::Jder;
+    ///
+    /// async fn route() -> Jder<String> {
+    ///     Jder::created("Name".to_string())
+    /// }
+    /// ```
+    pub fn created(data: T) -> Self {
+        Self { status: StatusCode::CREATED, data }
+    }
+
+    /// Create a success JSON response with status code `202`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::Jder;
+    ///
+    /// async fn route() -> Jder<String> {
+    ///     Jder::accepted("Name".to_string())
+    /// }
+    /// ```
+    pub fn accepted(data: T) -> Self {
+        Self { status: StatusCode::ACCEPTED, data }
+    }
+
+    /// Create a success JSON response with a status code.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::json::Jder;
+    ///
+    /// async fn route() -> Jder<String> {
+    ///     Jder::with_status(
+    ///         StatusCode::NON_AUTHORITATIVE_INFORMATION,
+    ///         "Name".to_string(),
+    ///     )
+    /// }
+    /// ```
+    pub fn with_status<S: Into<StatusCode>>(
+        status: S,
+        data: T,
+    ) -> Self {
+        Self { status: status.into(), data }
+    }
+
+    /// Get the status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Get the data of the response.
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+impl<T> From<T> for Jder<T> {
+    fn from(data: T) -> Self {
+        Self::ok(data)
+    }
+}
+
+impl<T> IntoResponse for Jder<T>
+where
+    T: Serialize + Send + 'static,
+{
+    fn into_response(self) -> Response {
+        CreateJsonResponse::success::<T>()
+            .status(self.status)
+            .data(self.data)
+            .send()
+    }
+}
@@ -1,23 +1,67 @@
+pub(crate) mod config;
 pub(crate) mod error;
 pub(crate) mod failure;
+pub(crate) mod integers;
+pub(crate) mod jder;
+#[cfg(feature = "negotiate")]
+pub(crate) mod negotiate;
+pub(crate) mod pagination;
+pub(crate) mod problem;
+pub(crate) mod redirect;
+#[cfg(feature = "stream")]
+pub(crate) mod stream;
 pub(crate) mod success;
 
 pub use crate::response::json::success::JsonSuccessResponseFunctions;
 
 pub use crate::response::json::failure::JsonFailureResponseFunctions;
 
-pub use crate::response::json::error::JsonResponseErrorCode;
+pub use crate::response::json::jder::Jder;
 
-use axum_core::{body::Body, response::Response};
-use http::{
-    HeaderMap, HeaderValue, StatusCode, Version, header, response::Builder,
+#[cfg(feature = "negotiate")]
+pub use crate::response::json::negotiate::{Negotiated, NegotiatedFormat};
+
+#[cfg(feature = "json_config")]
+pub use crate::response::json::config::{JsonPretty, JsonResponseConfig};
+
+pub use crate::response::json::pagination::PageInfo;
+
+pub use crate::response::json::problem::{
+    PROBLEM_JSON_CONTENT_TYPE, ProblemDetails,
+};
+
+pub use crate::response::json::redirect::RedirectData;
+
+#[cfg(feature = "cursor")]
+pub use crate::response::json::pagination::CursorCodec;
+
+pub use crate::response::json::error::{
+    ErrorCode, JsonResponseErrorCode, ParseJsonResponseErrorCodeError,
+};
+
+use axum_core::{
+    body::Body,
+    response::{IntoResponse, Response},
 };
+use http::{HeaderMap, HeaderValue, StatusCode, Version, header};
 use serde::{Deserialize, Serialize};
 
-use crate::response::json::error::FAILURE_RESPONSE_DEFAULT;
+use crate::{
+    error::JderError,
+    response::{
+        header::create_location,
+        json::{
+            config::{JsonOutput, create_json_body},
+            error::FAILURE_RESPONSE_DEFAULT,
+        },
+    },
+};
 
 /// JSON response error.
 ///
+/// Can be returned from routes as a failure JSON response,
+/// with status code inferred from the error code.
+///
 /// For API documentation generation with utoipa,
 /// `ToSchema` derive is available with the `utoipa` feature.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -33,8 +77,36 @@ pub struct JsonResponseError {
 
 /// JSON response.
 ///
+/// Can be returned from routes as a JSON response,
+/// with status code `200` for success responses,
+/// and status code inferred from the error code for failure responses.
+/// Status code `400` is used if the error code is unknown.
+///
 /// For API documentation generation with utoipa,
 /// `ToSchema` derive is available with the `utoipa` feature.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::{
+///     JsonResponse,
+///     JsonResponseError,
+///     JsonResponseErrorCode,
+/// };
+///
+/// async fn route() -> JsonResponse {
+///     JsonResponse {
+///         success: false,
+///         data: None,
+///         error: Some(JsonResponseError {
+///             code: JsonResponseErrorCode::NotFound.to_string(),
+///             field: None,
+///             message: None,
+///         }),
+///         ..Default::default()
+///     }
+/// }
+/// ```
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct JsonResponse<D = ()> {
@@ -44,6 +116,22 @@ pub struct JsonResponse<D = ()> {
     pub data: Option<D>,
     /// Error for the response when `success` is `false`.
     pub error: Option<JsonResponseError>,
+    /// All errors for the response when `success` is `false`,
+    /// only serialized when errors are pushed with
+    /// [`push_error`](JsonFailureResponseFunctions::push_error).
+    ///
+    /// For clients that only read a single error,
+    /// `error` is the first pushed error unless an error is set
+    /// with the builder, e.g. with a code of
+    /// [`unprocessable_entity`](CreateJsonResponse::unprocessable_entity).
+    /// Setting the error never changes the pushed errors.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<JsonResponseError>,
+    /// Pagination for the response when `success` is `true`,
+    /// only serialized when set with
+    /// [`paginate`](JsonSuccessResponseFunctions::paginate).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<PageInfo>,
 }
 
 /// Internal state.
@@ -52,65 +140,113 @@ pub(crate) struct JsonResponseState<D> {
     status: StatusCode,
     version: Version,
     header_map: HeaderMap,
-    is_header_map_failed: bool,
+    header_map_error: Option<JsonResponseError>,
     success: bool,
     data: Option<D>,
     error: Option<JsonResponseError>,
+    errors: Vec<JsonResponseError>,
+    pagination: Option<PageInfo>,
 }
 
-pub(crate) fn create_json_response_send<D: Serialize>(
-    state: JsonResponseState<D>
-) -> Response {
-    let server_error: Response = Response::builder()
-        .status(StatusCode::INTERNAL_SERVER_ERROR)
-        .header(header::CONTENT_TYPE, "application/json")
-        .body(Body::from(FAILURE_RESPONSE_DEFAULT.to_string()))
-        .unwrap();
-
-    // header map error
-    if state.is_header_map_failed {
-        // create error
-        let res_error: JsonResponseError = JsonResponseError {
-            code: JsonResponseErrorCode::Parse.to_string(),
-            field: Some("header_map".to_string()),
-            message: Some("Failed to create header map.".to_string()),
-        };
+impl<D> JsonResponse<D> {
+    /// Infer the status code from the success flag and the error code.
+    pub(crate) fn infer_status(&self) -> StatusCode {
+        match (self.success, &self.error) {
+            | (true, _) => StatusCode::OK,
+            | (false, Some(error)) => {
+                match error.code.parse::<JsonResponseErrorCode>() {
+                    | Ok(code) => code.status(),
+                    | Err(_) => StatusCode::BAD_REQUEST,
+                }
+            },
+            | (false, None) => StatusCode::BAD_REQUEST,
+        }
+    }
+}
 
-        let res: JsonResponse<D> =
-            JsonResponse { success: false, data: None, error: Some(res_error) };
+impl<D: Serialize + Send + 'static> IntoResponse for JsonResponse<D> {
+    fn into_response(self) -> Response {
+        let status: StatusCode = self.infer_status();
 
-        // parse body
-        let body: String = match serde_json::to_string(&res) {
-            | Ok(body) => body,
-            | Err(_) => {
-                return server_error;
-            },
-        };
+        create_json_response_send(JsonResponseState {
+            status,
+            version: Version::HTTP_11,
+            header_map: HeaderMap::new(),
+            header_map_error: None,
+            success: self.success,
+            data: self.data,
+            error: self.error,
+            errors: self.errors,
+            pagination: self.pagination,
+        })
+    }
+}
 
-        return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .header(header::CONTENT_TYPE, "application/json")
-            .body(Body::from(body))
-            .unwrap();
+impl IntoResponse for JsonResponseError {
+    fn into_response(self) -> Response {
+        JsonResponse::<()> {
+            success: false,
+            data: None,
+            error: Some(self),
+            errors: Vec::new(),
+            pagination: None,
+        }
+        .into_response()
     }
+}
 
-    // create response builder
-    let mut builder: Builder =
-        Response::builder().status(state.status).version(state.version);
+/// Create the default server error response,
+/// used when a JSON body cannot be serialized.
+pub(crate) fn create_json_server_error() -> Response {
+    let mut res: Response = Response::new(Body::from(FAILURE_RESPONSE_DEFAULT));
 
-    // set content type
-    let mut header_map: HeaderMap = state.header_map;
+    *res.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
 
-    header_map.append(
+    res.headers_mut().insert(
         header::CONTENT_TYPE,
-        HeaderValue::from_str("application/json").unwrap(),
+        HeaderValue::from_static("application/json"),
     );
 
-    // push headers
-    for (header, value) in header_map {
-        if let Some(header) = header {
-            builder = builder.header(header, value);
-        }
+    res
+}
+
+/// Create a response with the JSON body of a response,
+/// serialized with the output of the request.
+fn create_json_response_body<D: Serialize + Send + 'static>(
+    res: JsonResponse<D>
+) -> Result<Response, serde_json::Error> {
+    create_json_body(res, |res: &JsonResponse<D>, output: &JsonOutput| {
+        output.response_to_bytes(res)
+    })
+}
+
+pub(crate) fn create_json_response_send<D: Serialize + Send + 'static>(
+    state: JsonResponseState<D>
+) -> Response {
+    // header map error
+    if let Some(res_error) = state.header_map_error {
+        let res: JsonResponse<D> = JsonResponse {
+            success: false,
+            data: None,
+            error: Some(res_error),
+            errors: Vec::new(),
+            pagination: None,
+        };
+
+        // parse body
+        let mut res: Response = match create_json_response_body(res) {
+            | Ok(res) => res,
+            | Err(_) => return create_json_server_error(),
+        };
+
+        *res.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+
+        res.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+
+        return res;
     }
 
     // create response
@@ -118,18 +254,42 @@ pub(crate) fn create_json_response_send<D: Serialize>(
         success: state.success,
         data: state.data,
         error: state.error,
+        errors: state.errors,
+        pagination: state.pagination,
     };
 
     // parse body
-    let body: String = match serde_json::to_string(&res) {
-        | Ok(body) => body,
-        | Err(_) => {
-            return server_error;
-        },
+    let mut res: Response = match create_json_response_body(res) {
+        | Ok(res) => res,
+        | Err(_) => return create_json_server_error(),
     };
 
+    // set content type
+    let mut header_map: HeaderMap = state.header_map;
+
+    header_map
+        .entry(header::CONTENT_TYPE)
+        .or_insert(HeaderValue::from_static("application/json"));
+
     // result
-    builder.body(Body::from(body)).unwrap()
+    *res.status_mut() = state.status;
+    *res.version_mut() = state.version;
+    *res.headers_mut() = header_map;
+
+    res
+}
+
+pub(crate) fn create_json_response_try_send<D: Serialize + Send + 'static>(
+    mut state: JsonResponseState<D>
+) -> Result<Response, JderError> {
+    if let Some(err) = state.header_map_error.take() {
+        return Err(JderError::from_json_response_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            err,
+        ));
+    }
+
+    Ok(create_json_response_send(state))
 }
 
 /// Create a JSON response for a route.
@@ -206,10 +366,12 @@ impl CreateJsonResponse {
                 status: StatusCode::OK,
                 version: Version::HTTP_11,
                 header_map: HeaderMap::new(),
-                is_header_map_failed: false,
+                header_map_error: None,
                 success: true,
                 data: None,
                 error: None,
+                errors: Vec::new(),
+                pagination: None,
             },
         }
     }
@@ -242,14 +404,96 @@ impl CreateJsonResponse {
                 status: StatusCode::OK,
                 version: Version::HTTP_11,
                 header_map: HeaderMap::new(),
-                is_header_map_failed: false,
+                header_map_error: None,
                 success: true,
                 data: None,
                 error: None,
+                errors: Vec::new(),
+                pagination: None,
             },
         }
     }
 
+    /// Create a redirect JSON response with status code `307`,
+    /// keeping the method and body of the request.
+    ///
+    /// The location is set in the `Location` header
+    /// and the `data` of the body.
+    /// It must be an absolute `http` / `https` URL or an absolute path,
+    /// or an internal server error will be sent instead.
+    /// The location is not resolved against the request,
+    /// so relative references such as `users/1` or `?page=2`
+    /// must be resolved with
+    /// [`RequestBase::resolve`](crate::extract::extra::RequestBase::resolve)
+    /// before being passed.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::redirect_temporary("/v2/users").send()
+    /// }
+    /// ```
+    pub fn redirect_temporary(
+        location: &str
+    ) -> JsonSuccessResponseFunctions<RedirectData> {
+        create_json_redirect(StatusCode::TEMPORARY_REDIRECT, location)
+    }
+
+    /// Create a redirect JSON response with status code `308`,
+    /// keeping the method and body of the request.
+    ///
+    /// See [`redirect_temporary`](Self::redirect_temporary)
+    /// for the location.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::redirect_permanent("/v2/users").send()
+    /// }
+    /// ```
+    pub fn redirect_permanent(
+        location: &str
+    ) -> JsonSuccessResponseFunctions<RedirectData> {
+        create_json_redirect(StatusCode::PERMANENT_REDIRECT, location)
+    }
+
+    /// Create a redirect JSON response with status code `303`,
+    /// telling the client to `GET` the location,
+    /// e.g. after creating a resource.
+    ///
+    /// See [`redirect_temporary`](Self::redirect_temporary)
+    /// for the location.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::redirect_see_other("/users/1").send()
+    /// }
+    /// ```
+    pub fn redirect_see_other(
+        location: &str
+    ) -> JsonSuccessResponseFunctions<RedirectData> {
+        create_json_redirect(StatusCode::SEE_OTHER, location)
+    }
+
     /// Create a failure JSON response.
     ///
     /// ## Example
@@ -270,11 +514,371 @@ impl CreateJsonResponse {
                 status: StatusCode::BAD_REQUEST,
                 version: Version::HTTP_11,
                 header_map: HeaderMap::new(),
-                is_header_map_failed: false,
+                header_map_error: None,
                 success: false,
                 data: None,
                 error: None,
+                errors: Vec::new(),
+                pagination: None,
             },
         }
     }
+
+    /// Create an internal server error failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::Server`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::server_error().send()
+    /// }
+    /// ```
+    pub fn server_error() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::Server)
+    }
+
+    /// Create a bad request failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::BadRequest`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::bad_request().send()
+    /// }
+    /// ```
+    pub fn bad_request() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::BadRequest)
+    }
+
+    /// Create an unauthorized failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::Unauthorized`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::unauthorized().send()
+    /// }
+    /// ```
+    pub fn unauthorized() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::Unauthorized)
+    }
+
+    /// Create a forbidden failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::Forbidden`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::forbidden().send()
+    /// }
+    /// ```
+    pub fn forbidden() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::Forbidden)
+    }
+
+    /// Create a not found failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::NotFound`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::not_found().send()
+    /// }
+    /// ```
+    pub fn not_found() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::NotFound)
+    }
+
+    /// Create a method not allowed failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::MethodNotAllowed`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::method_not_allowed().send()
+    /// }
+    /// ```
+    pub fn method_not_allowed() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::MethodNotAllowed)
+    }
+
+    /// Create a not acceptable failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::NotAcceptable`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::not_acceptable().send()
+    /// }
+    /// ```
+    pub fn not_acceptable() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::NotAcceptable)
+    }
+
+    /// Create a conflict failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::Conflict`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::conflict().send()
+    /// }
+    /// ```
+    pub fn conflict() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::Conflict)
+    }
+
+    /// Create a gone failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::Gone`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::gone().send()
+    /// }
+    /// ```
+    pub fn gone() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::Gone)
+    }
+
+    /// Create a precondition failed failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::PreconditionFailed`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::precondition_failed().send()
+    /// }
+    /// ```
+    pub fn precondition_failed() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::PreconditionFailed)
+    }
+
+    /// Create an unsupported media type failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::UnsupportedMediaType`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::unsupported_media_type().send()
+    /// }
+    /// ```
+    pub fn unsupported_media_type() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::UnsupportedMediaType)
+    }
+
+    /// Create a range not satisfiable failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::RangeNotSatisfiable`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::range_not_satisfiable().send()
+    /// }
+    /// ```
+    pub fn range_not_satisfiable() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::RangeNotSatisfiable)
+    }
+
+    /// Create an unprocessable entity failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::UnprocessableEntity`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::unprocessable_entity().send()
+    /// }
+    /// ```
+    pub fn unprocessable_entity() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::UnprocessableEntity)
+    }
+
+    /// Create a precondition required failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::PreconditionRequired`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::precondition_required().send()
+    /// }
+    /// ```
+    pub fn precondition_required() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::PreconditionRequired)
+    }
+
+    /// Create a too many requests failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::TooManyRequests`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::too_many_requests().send()
+    /// }
+    /// ```
+    pub fn too_many_requests() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::TooManyRequests)
+    }
+
+    /// Create a not implemented failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::NotImplemented`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::not_implemented().send()
+    /// }
+    /// ```
+    pub fn not_implemented() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::NotImplemented)
+    }
+
+    /// Create a service unavailable failure JSON response,
+    /// with status code and error code from
+    /// [`JsonResponseErrorCode::ServiceUnavailable`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::service_unavailable().send()
+    /// }
+    /// ```
+    pub fn service_unavailable() -> JsonFailureResponseFunctions<()> {
+        Self::failure().code(JsonResponseErrorCode::ServiceUnavailable)
+    }
+}
+
+/// Create a redirect JSON response with a status code and location.
+fn create_json_redirect(
+    status: StatusCode,
+    location: &str,
+) -> JsonSuccessResponseFunctions<RedirectData> {
+    let mut res: JsonSuccessResponseFunctions<RedirectData> =
+        CreateJsonResponse::success().status(status);
+
+    match create_location(location) {
+        | Ok(value) => {
+            res.state.header_map.insert(header::LOCATION, value);
+        },
+        | Err(err) => res.state.header_map_error = Some(err),
+    }
+
+    res.data(RedirectData { location: location.to_string() })
 }
@@ -0,0 +1,248 @@
+use axum_core::{body::Body, response::IntoResponse};
+use http::{HeaderMap, HeaderValue, StatusCode, Version, header};
+use serde::Serialize;
+
+use crate::{
+    error::JderError,
+    response::{
+        Response,
+        json::{
+            JsonResponse, JsonResponseState, create_json_response_send,
+            create_json_server_error,
+        },
+    },
+};
+
+/// Format of a negotiated response.
+///
+/// Available with `negotiate` feature.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NegotiatedFormat {
+    /// JSON, `application/json`.
+    #[default]
+    Json,
+    /// MessagePack, `application/msgpack`.
+    MessagePack,
+    /// CBOR, `application/cbor`.
+    Cbor,
+}
+
+impl NegotiatedFormat {
+    /// All formats in the order of preference
+    /// when clients accept them equally.
+    pub const ALL: [Self; 3] = [Self::Json, Self::MessagePack, Self::Cbor];
+
+    /// Get the media type of the format.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::NegotiatedFormat;
+    ///
+    /// assert_eq!(NegotiatedFormat::Cbor.media_type(), "application/cbor");
+    /// ```
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            | Self::Json => "application/json",
+            | Self::MessagePack => "application/msgpack",
+            | Self::Cbor => "application/cbor",
+        }
+    }
+
+    /// Check if a media type without parameters is the format,
+    /// including aliases such as `application/x-msgpack`.
+    pub(crate) fn matches(
+        &self,
+        mime: &str,
+    ) -> bool {
+        match self {
+            | Self::Json => mime.eq_ignore_ascii_case("application/json"),
+            | Self::MessagePack => {
+                mime.eq_ignore_ascii_case("application/msgpack")
+                    || mime.eq_ignore_ascii_case("application/x-msgpack")
+                    || mime.eq_ignore_ascii_case("application/vnd.msgpack")
+            },
+            | Self::Cbor => mime.eq_ignore_ascii_case("application/cbor"),
+        }
+    }
+
+    /// Serialize a response into bytes of a binary format.
+    fn to_binary<D: Serialize>(
+        self,
+        res: &JsonResponse<D>,
+    ) -> Option<Vec<u8>> {
+        match self {
+            | Self::Json => None,
+            | Self::MessagePack => rmp_serde::to_vec_named(res).ok(),
+            | Self::Cbor => {
+                let mut buf: Vec<u8> = Vec::new();
+
+                ciborium::into_writer(res, &mut buf).ok().map(|_| buf)
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for NegotiatedFormat {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.write_str(self.media_type())
+    }
+}
+
+/// Response serialized in the format negotiated with
+/// [`Negotiate`](crate::extract::Negotiate).
+///
+/// The status code is inferred from the response by default,
+/// as in [`JsonResponse`].
+///
+/// Available with `negotiate` feature.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     extract::Negotiate,
+///     response::json::Negotiated,
+/// };
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct User {
+///     id: usize,
+/// }
+///
+/// async fn route(negotiate: Negotiate) -> Negotiated<User> {
+///     negotiate.ok(User { id: 1 })
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Negotiated<D = ()> {
+    format: NegotiatedFormat,
+    status: Option<StatusCode>,
+    response: JsonResponse<D>,
+}
+
+impl<D> Negotiated<D> {
+    /// Create a negotiated response from a format and a response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::{
+    ///     JsonResponse,
+    ///     Negotiated,
+    ///     NegotiatedFormat,
+    /// };
+    ///
+    /// async fn route() -> Negotiated {
+    ///     Negotiated::new(NegotiatedFormat::Cbor, JsonResponse::default())
+    /// }
+    /// ```
+    pub fn new(
+        format: NegotiatedFormat,
+        response: JsonResponse<D>,
+    ) -> Self {
+        Self { format, status: None, response }
+    }
+
+    /// Set the status code of the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::{
+    ///     extract::Negotiate,
+    ///     response::json::Negotiated,
+    /// };
+    ///
+    /// async fn route(negotiate: Negotiate) -> Negotiated<String> {
+    ///     negotiate.ok("Name".to_string()).status(StatusCode::CREATED)
+    /// }
+    /// ```
+    pub fn status<S: Into<StatusCode>>(
+        mut self,
+        status: S,
+    ) -> Self {
+        self.status = Some(status.into());
+
+        self
+    }
+
+    /// Get the format of the response.
+    pub fn format(&self) -> NegotiatedFormat {
+        self.format
+    }
+}
+
+impl Negotiated<()> {
+    /// Create a negotiated failure response from an error,
+    /// with the status code of the error.
+    pub(crate) fn from_error(
+        format: NegotiatedFormat,
+        error: JderError,
+    ) -> Self {
+        let status: StatusCode = error.status;
+
+        Self::new(
+            format,
+            JsonResponse {
+                success: false,
+                data: None,
+                error: Some(error.to_json_response_error()),
+                errors: Vec::new(),
+                pagination: None,
+            },
+        )
+        .status(status)
+    }
+}
+
+impl<D: Serialize + Send + 'static> IntoResponse for Negotiated<D> {
+    fn into_response(self) -> Response {
+        let status: StatusCode =
+            self.status.unwrap_or_else(|| self.response.infer_status());
+
+        let mut res: Response = match self.format {
+            | NegotiatedFormat::Json => {
+                create_json_response_send(JsonResponseState {
+                    status,
+                    version: Version::HTTP_11,
+                    header_map: HeaderMap::new(),
+                    header_map_error: None,
+                    success: self.response.success,
+                    data: self.response.data,
+                    error: self.response.error,
+                    errors: self.response.errors,
+                    pagination: self.response.pagination,
+                })
+            },
+            | format => {
+                let body: Vec<u8> = match format.to_binary(&self.response) {
+                    | Some(body) => body,
+                    | None => return create_json_server_error(),
+                };
+
+                let mut res: Response = Response::new(Body::from(body));
+
+                *res.status_mut() = status;
+
+                res.headers_mut().insert(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static(format.media_type()),
+                );
+
+                res
+            },
+        };
+
+        // the body depends on the `Accept` header
+        res.headers_mut()
+            .append(header::VARY, HeaderValue::from_static("accept"));
+
+        res
+    }
+}
@@ -0,0 +1,457 @@
+use http::Uri;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "cursor")]
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+#[cfg(feature = "cursor")]
+use hmac::{Hmac, Mac};
+#[cfg(feature = "cursor")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "cursor")]
+use sha2::Sha256;
+
+#[cfg(feature = "cursor")]
+use crate::{error::JderError, response::json::JsonResponseErrorCode};
+
+/// Query parameters replaced when creating `Link` headers.
+const PAGINATION_QUERY_KEYS: [&str; 3] = ["page", "per_page", "cursor"];
+
+/// Pagination information for a success JSON response.
+///
+/// Use with
+/// [`paginate`](crate::response::json::JsonSuccessResponseFunctions::paginate)
+/// to add the pagination to the response.
+///
+/// For API documentation generation with utoipa,
+/// `ToSchema` derive is available with the `utoipa` feature.
+///
+/// ## Examples
+///
+/// Page based pagination:
+///
+/// ```no_run
+/// use jder_axum::response::json::PageInfo;
+///
+/// let info: PageInfo = PageInfo::new(2, 20).with_total(100);
+/// ```
+///
+/// Cursor based pagination:
+///
+/// ```no_run
+/// use jder_axum::response::json::PageInfo;
+///
+/// let info: PageInfo = PageInfo::cursor(20).with_next_cursor("abc");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct PageInfo {
+    /// Current page starting from `1`, for page based pagination.
+    pub page: Option<u64>,
+    /// Number of items per page.
+    pub per_page: u64,
+    /// Total number of items.
+    pub total: Option<u64>,
+    /// Cursor of the next page, for cursor based pagination.
+    pub next_cursor: Option<String>,
+    /// Cursor of the previous page, for cursor based pagination.
+    pub prev_cursor: Option<String>,
+    /// URI for creating `Link` headers.
+    #[serde(skip)]
+    uri: Option<Uri>,
+}
+
+impl PageInfo {
+    /// Create a page based pagination.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::PageInfo;
+    ///
+    /// let info: PageInfo = PageInfo::new(1, 20);
+    /// ```
+    pub fn new(
+        page: u64,
+        per_page: u64,
+    ) -> Self {
+        Self { page: Some(page), per_page, ..Default::default() }
+    }
+
+    /// Create a cursor based pagination.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::PageInfo;
+    ///
+    /// let info: PageInfo = PageInfo::cursor(20);
+    /// ```
+    pub fn cursor(per_page: u64) -> Self {
+        Self { per_page, ..Default::default() }
+    }
+
+    /// Set the total number of items.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::PageInfo;
+    ///
+    /// let info: PageInfo = PageInfo::new(1, 20).with_total(100);
+    /// ```
+    pub fn with_total(
+        mut self,
+        total: u64,
+    ) -> Self {
+        self.total = Some(total);
+
+        self
+    }
+
+    /// Set the cursor of the next page.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::PageInfo;
+    ///
+    /// let info: PageInfo = PageInfo::cursor(20).with_next_cursor("abc");
+    /// ```
+    pub fn with_next_cursor<S: Into<String>>(
+        mut self,
+        cursor: S,
+    ) -> Self {
+        self.next_cursor = Some(cursor.into());
+
+        self
+    }
+
+    /// Set the cursor of the previous page.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::PageInfo;
+    ///
+    /// let info: PageInfo = PageInfo::cursor(20).with_prev_cursor("abc");
+    /// ```
+    pub fn with_prev_cursor<S: Into<String>>(
+        mut self,
+        cursor: S,
+    ) -> Self {
+        self.prev_cursor = Some(cursor.into());
+
+        self
+    }
+
+    /// Set the URI of the request for creating RFC 8288 `Link` headers.
+    ///
+    /// The `page`, `per_page` and `cursor` query parameters
+    /// will be replaced in the links, while others are kept.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::Uri;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{CreateJsonResponse, PageInfo},
+    /// };
+    ///
+    /// async fn route(uri: Uri) -> Response {
+    ///     CreateJsonResponse::success::<Vec<String>>()
+    ///         .data(vec![])
+    ///         .paginate(PageInfo::new(1, 20).with_total(100).with_uri(uri))
+    ///         .send()
+    /// }
+    /// ```
+    pub fn with_uri<U: Into<Uri>>(
+        mut self,
+        uri: U,
+    ) -> Self {
+        self.uri = Some(uri.into());
+
+        self
+    }
+
+    /// Create a link to the URI with pagination query parameters.
+    ///
+    /// Query parameters are percent-encoded,
+    /// so values such as cursors are kept intact.
+    fn create_link(
+        uri: &Uri,
+        params: &[(&str, String)],
+        rel: &str,
+    ) -> String {
+        let mut link: String = String::new();
+
+        if let (Some(scheme), Some(authority)) =
+            (uri.scheme_str(), uri.authority())
+        {
+            link.push_str(scheme);
+            link.push_str("://");
+            link.push_str(authority.as_str());
+        }
+
+        link.push_str(uri.path());
+
+        let mut query: form_urlencoded::Serializer<String> =
+            form_urlencoded::Serializer::new(String::new());
+
+        if let Some(prev) = uri.query() {
+            query.extend_pairs(form_urlencoded::parse(prev.as_bytes()).filter(
+                |(key, _)| !PAGINATION_QUERY_KEYS.contains(&key.as_ref()),
+            ));
+        }
+
+        query.extend_pairs(params);
+
+        format!("<{}?{}>; rel=\"{}\"", link, query.finish(), rel)
+    }
+
+    /// Create the value of the `Link` header,
+    /// `None` if no URI is set.
+    pub(crate) fn to_link_header(&self) -> Option<String> {
+        let uri: &Uri = self.uri.as_ref()?;
+
+        let per_page: String = self.per_page.to_string();
+
+        let mut links: Vec<String> = Vec::new();
+
+        match self.page {
+            | Some(page) => {
+                let last: Option<u64> = match (self.total, self.per_page) {
+                    | (Some(total), per_page) if per_page > 0 => {
+                        Some(total.div_ceil(per_page).max(1))
+                    },
+                    | _ => None,
+                };
+
+                links.push(Self::create_link(
+                    uri,
+                    &[
+                        ("page", "1".to_string()),
+                        ("per_page", per_page.clone()),
+                    ],
+                    "first",
+                ));
+
+                if page > 1 {
+                    links.push(Self::create_link(
+                        uri,
+                        &[
+                            ("page", (page - 1).to_string()),
+                            ("per_page", per_page.clone()),
+                        ],
+                        "prev",
+                    ));
+                }
+
+                // next page is assumed to exist when total is unknown
+                if last.is_none_or(|last| page < last) {
+                    links.push(Self::create_link(
+                        uri,
+                        &[
+                            ("page", (page + 1).to_string()),
+                            ("per_page", per_page.clone()),
+                        ],
+                        "next",
+                    ));
+                }
+
+                if let Some(last) = last {
+                    links.push(Self::create_link(
+                        uri,
+                        &[("page", last.to_string()), ("per_page", per_page)],
+                        "last",
+                    ));
+                }
+            },
+            | None => {
+                if let Some(cursor) = &self.prev_cursor {
+                    links.push(Self::create_link(
+                        uri,
+                        &[
+                            ("cursor", cursor.clone()),
+                            ("per_page", per_page.clone()),
+                        ],
+                        "prev",
+                    ));
+                }
+
+                if let Some(cursor) = &self.next_cursor {
+                    links.push(Self::create_link(
+                        uri,
+                        &[("cursor", cursor.clone()), ("per_page", per_page)],
+                        "next",
+                    ));
+                }
+            },
+        }
+
+        match links.is_empty() {
+            | true => None,
+            | false => Some(links.join(", ")),
+        }
+    }
+}
+
+/// Encoder and decoder for opaque, tamper-evident cursors,
+/// available with `cursor` feature.
+///
+/// The value is serialized as JSON and signed with HMAC-SHA256,
+/// then encoded as URL safe base64,
+/// so the cursor can be safely passed in query parameters.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     error::JderError,
+///     extract::Query,
+///     response::{
+///         Response,
+///         json::{CreateJsonResponse, CursorCodec, PageInfo},
+///     },
+/// };
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Cursor {
+///     last_id: u64,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Params {
+///     cursor: Option<String>,
+/// }
+///
+/// async fn route(
+///     Query(params): Query<Params>,
+/// ) -> Result<Response, JderError> {
+///     let codec: CursorCodec = CursorCodec::new("secret");
+///
+///     let last_id: u64 = match params.cursor {
+///         | Some(cursor) => codec.decode::<Cursor>(&cursor)?.last_id,
+///         | None => 0,
+///     };
+///
+///     let next: String = codec.encode(&Cursor { last_id: last_id + 20 })?;
+///
+///     Ok(CreateJsonResponse::success::<Vec<u64>>()
+///         .data(vec![])
+///         .paginate(PageInfo::cursor(20).with_next_cursor(next))
+///         .send())
+/// }
+/// ```
+#[cfg(feature = "cursor")]
+#[derive(Clone)]
+pub struct CursorCodec {
+    key: Vec<u8>,
+}
+
+#[cfg(feature = "cursor")]
+impl CursorCodec {
+    /// Create a new cursor codec with a secret key.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::CursorCodec;
+    ///
+    /// let codec: CursorCodec = CursorCodec::new("secret");
+    /// ```
+    pub fn new<K: AsRef<[u8]>>(key: K) -> Self {
+        Self { key: key.as_ref().to_vec() }
+    }
+
+    fn sign(
+        &self,
+        payload: &[u8],
+    ) -> Result<Hmac<Sha256>, JderError> {
+        match Hmac::<Sha256>::new_from_slice(&self.key) {
+            | Ok(mut mac) => {
+                mac.update(payload);
+                Ok(mac)
+            },
+            | Err(err) => Err(JderError::new(JsonResponseErrorCode::Server)
+                .with_field("cursor")
+                .with_message(err.to_string())),
+        }
+    }
+
+    /// Encode a value into a cursor.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::CursorCodec;
+    ///
+    /// let codec: CursorCodec = CursorCodec::new("secret");
+    ///
+    /// let cursor: String = codec.encode(&20).unwrap();
+    /// ```
+    pub fn encode<T: Serialize>(
+        &self,
+        value: &T,
+    ) -> Result<String, JderError> {
+        let payload: Vec<u8> = serde_json::to_vec(value)?;
+
+        let signature = self.sign(&payload)?.finalize().into_bytes();
+
+        Ok(format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(&payload),
+            URL_SAFE_NO_PAD.encode(signature)
+        ))
+    }
+
+    /// Decode a cursor into a value.
+    ///
+    /// Returns an error with `parse` code and `cursor` field
+    /// if the cursor is malformed or has been tampered with.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::CursorCodec;
+    ///
+    /// let codec: CursorCodec = CursorCodec::new("secret");
+    ///
+    /// let cursor: String = codec.encode(&20).unwrap();
+    ///
+    /// let value: u64 = codec.decode(&cursor).unwrap();
+    /// ```
+    pub fn decode<T: DeserializeOwned>(
+        &self,
+        cursor: &str,
+    ) -> Result<T, JderError> {
+        let invalid = || {
+            JderError::new(JsonResponseErrorCode::Parse)
+                .with_field("cursor")
+                .with_message("Invalid cursor.")
+        };
+
+        let (payload, signature) =
+            cursor.split_once('.').ok_or_else(invalid)?;
+
+        let payload: Vec<u8> =
+            URL_SAFE_NO_PAD.decode(payload).map_err(|_| invalid())?;
+        let signature: Vec<u8> =
+            URL_SAFE_NO_PAD.decode(signature).map_err(|_| invalid())?;
+
+        self.sign(&payload)?.verify_slice(&signature).map_err(|_| invalid())?;
+
+        serde_json::from_slice(&payload).map_err(|_| invalid())
+    }
+}
+
+#[cfg(feature = "cursor")]
+impl std::fmt::Debug for CursorCodec {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_struct("CursorCodec").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,275 @@
+use axum_core::{body::Body, response::IntoResponse};
+use http::{HeaderValue, StatusCode, header};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    error::JderError,
+    response::{
+        Response,
+        json::{
+            JsonResponse, JsonResponseError, JsonResponseErrorCode,
+            create_json_server_error,
+        },
+    },
+};
+
+/// Default type of problem details.
+const PROBLEM_TYPE_DEFAULT: &str = "about:blank";
+
+/// Content type of problem details.
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
+fn problem_type_default() -> String {
+    PROBLEM_TYPE_DEFAULT.to_string()
+}
+
+/// Problem details of [RFC 9457](https://www.rfc-editor.org/rfc/rfc9457),
+/// sent as `application/problem+json`.
+///
+/// Converted from JSON responses and errors,
+/// with the error code, field and errors as extension members.
+///
+/// For sending problem details to clients that ask for them,
+/// see `ProblemDetailsLayer`, available with `problem_details` feature.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::http::StatusCode;
+/// use jder_axum::response::json::ProblemDetails;
+///
+/// async fn route() -> ProblemDetails {
+///     ProblemDetails::new(StatusCode::FORBIDDEN)
+///         .with_type("https://example.com/probs/out-of-credit")
+///         .with_detail("Your current balance is 30, but that costs 50.")
+///         .with_extension("balance", 30)
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProblemDetails {
+    /// URI reference that identifies the problem type,
+    /// `about:blank` by default.
+    #[serde(rename = "type", default = "problem_type_default")]
+    pub r#type: String,
+    /// Short summary of the problem type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Status code of the response.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// Explanation specific to this occurrence of the problem.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// URI reference that identifies this occurrence of the problem.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    /// Extension members.
+    #[serde(flatten)]
+    pub extensions: Map<String, Value>,
+}
+
+impl ProblemDetails {
+    /// Create problem details with a status code,
+    /// using the reason of the status code as the title.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::json::ProblemDetails;
+    ///
+    /// let problem: ProblemDetails = ProblemDetails::new(StatusCode::NOT_FOUND);
+    /// ```
+    pub fn new<S: Into<StatusCode>>(status: S) -> Self {
+        let status: StatusCode = status.into();
+
+        Self {
+            r#type: problem_type_default(),
+            title: status.canonical_reason().map(|reason| reason.to_string()),
+            status: Some(status.as_u16()),
+            detail: None,
+            instance: None,
+            extensions: Map::new(),
+        }
+    }
+
+    /// Create problem details from a JSON response error with a status code.
+    ///
+    /// The message of the error is used as `detail`,
+    /// and the code and field are added as extension members.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::json::{
+    ///     JsonResponseError,
+    ///     JsonResponseErrorCode,
+    ///     ProblemDetails,
+    /// };
+    ///
+    /// let problem: ProblemDetails = ProblemDetails::from_json_response_error(
+    ///     StatusCode::NOT_FOUND,
+    ///     &JsonResponseError {
+    ///         code: JsonResponseErrorCode::NotFound.to_string(),
+    ///         field: Some("id".to_string()),
+    ///         message: Some("User not found.".to_string()),
+    ///     },
+    /// );
+    /// ```
+    pub fn from_json_response_error<S: Into<StatusCode>>(
+        status: S,
+        error: &JsonResponseError,
+    ) -> Self {
+        let mut problem: Self = Self::new(status);
+
+        problem.detail = error.message.clone();
+
+        problem
+            .extensions
+            .insert("code".to_string(), Value::String(error.code.clone()));
+
+        if let Some(field) = &error.field {
+            problem
+                .extensions
+                .insert("field".to_string(), Value::String(field.clone()));
+        }
+
+        problem
+    }
+
+    /// Create problem details from a failure JSON response
+    /// with a status code.
+    ///
+    /// The first error is converted as in
+    /// [`from_json_response_error`](Self::from_json_response_error),
+    /// and all errors are added as the `errors` extension member if any.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::json::{JsonResponse, ProblemDetails};
+    ///
+    /// let res: JsonResponse = JsonResponse::default();
+    ///
+    /// let problem: ProblemDetails =
+    ///     ProblemDetails::from_json_response(StatusCode::BAD_REQUEST, &res);
+    /// ```
+    pub fn from_json_response<S: Into<StatusCode>, D>(
+        status: S,
+        res: &JsonResponse<D>,
+    ) -> Self {
+        let mut problem: Self = match &res.error {
+            | Some(error) => Self::from_json_response_error(status, error),
+            | None => Self::new(status),
+        };
+
+        if !res.errors.is_empty() {
+            let errors: Value = serde_json::to_value(&res.errors)
+                .unwrap_or_else(|_| Value::Array(Vec::new()));
+
+            problem.extensions.insert("errors".to_string(), errors);
+        }
+
+        problem
+    }
+
+    /// Set the type of the problem.
+    pub fn with_type<S: Into<String>>(
+        mut self,
+        r#type: S,
+    ) -> Self {
+        self.r#type = r#type.into();
+
+        self
+    }
+
+    /// Set the title of the problem.
+    pub fn with_title<S: Into<String>>(
+        mut self,
+        title: S,
+    ) -> Self {
+        self.title = Some(title.into());
+
+        self
+    }
+
+    /// Set the detail of the problem.
+    pub fn with_detail<S: Into<String>>(
+        mut self,
+        detail: S,
+    ) -> Self {
+        self.detail = Some(detail.into());
+
+        self
+    }
+
+    /// Set the instance of the problem.
+    pub fn with_instance<S: Into<String>>(
+        mut self,
+        instance: S,
+    ) -> Self {
+        self.instance = Some(instance.into());
+
+        self
+    }
+
+    /// Add an extension member of the problem.
+    pub fn with_extension<K: Into<String>, V: Into<Value>>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Self {
+        self.extensions.insert(key.into(), value.into());
+
+        self
+    }
+}
+
+impl From<JsonResponseError> for ProblemDetails {
+    fn from(error: JsonResponseError) -> Self {
+        let status: StatusCode =
+            match error.code.parse::<JsonResponseErrorCode>() {
+                | Ok(code) => code.status(),
+                | Err(_) => StatusCode::BAD_REQUEST,
+            };
+
+        Self::from_json_response_error(status, &error)
+    }
+}
+
+impl From<JderError> for ProblemDetails {
+    fn from(error: JderError) -> Self {
+        Self::from_json_response_error(
+            error.status,
+            &error.to_json_response_error(),
+        )
+    }
+}
+
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> Response {
+        let status: StatusCode = self
+            .status
+            .and_then(|status| StatusCode::from_u16(status).ok())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+        let body: Vec<u8> = match serde_json::to_vec(&self) {
+            | Ok(body) => body,
+            | Err(_) => return create_json_server_error(),
+        };
+
+        let mut res: Response = Response::new(Body::from(body));
+
+        *res.status_mut() = status;
+
+        res.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(PROBLEM_JSON_CONTENT_TYPE),
+        );
+
+        res
+    }
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// Data of a redirect JSON response,
+/// carrying the target location of the redirect.
+///
+/// Created with redirect functions such as
+/// [`redirect_see_other`](crate::response::json::CreateJsonResponse::redirect_see_other).
+///
+/// For API documentation generation with utoipa,
+/// `ToSchema` derive is available with the `utoipa` feature.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::RedirectData;
+///
+/// let data: RedirectData = RedirectData {
+///     location: "/users/1".to_string(),
+/// };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct RedirectData {
+    /// Target location of the redirect.
+    pub location: String,
+}
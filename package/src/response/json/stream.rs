@@ -0,0 +1,172 @@
+use std::sync::{Arc, OnceLock};
+
+use axum_core::{BoxError, body::Body};
+use bytes::Bytes;
+use futures_util::stream::{self, Stream, StreamExt};
+use http::{HeaderMap, HeaderValue, header};
+use serde::Serialize;
+
+use crate::{
+    error::JderError,
+    response::{
+        Response,
+        json::{
+            JsonResponseState, PageInfo,
+            config::{JsonBody, JsonOutput},
+            create_json_response_send,
+        },
+    },
+};
+
+/// Closing chunk of a streaming JSON body that completes.
+const STREAM_END: &[u8] = b"],\"error\":null}";
+
+/// Phase of a streaming JSON body.
+enum JsonStreamPhase {
+    Start,
+    First,
+    Rest,
+    End,
+}
+
+/// Internal state of a streaming JSON body.
+struct JsonStreamState<S> {
+    phase: JsonStreamPhase,
+    stream: S,
+    pagination: Option<PageInfo>,
+    config: Arc<OnceLock<JsonOutput>>,
+    output: JsonOutput,
+}
+
+/// Create the opening chunk of a streaming JSON body.
+///
+/// `success` is written first as `true`,
+/// since the status code and headers are sent with this chunk,
+/// followed by `pagination` if set, and the opening of `data`.
+/// The body is aborted if the stream fails later,
+/// so a complete body always succeeds.
+fn create_json_stream_start(
+    pagination: Option<&PageInfo>,
+    output: &JsonOutput,
+) -> Bytes {
+    let mut buf: Vec<u8> = Vec::with_capacity(64);
+
+    buf.extend_from_slice(b"{\"success\":true");
+
+    if let Some(pagination) = pagination {
+        if let Ok(pagination) = output.to_bytes(pagination) {
+            buf.extend_from_slice(b",\"pagination\":");
+            buf.extend_from_slice(&pagination);
+        }
+    }
+
+    buf.extend_from_slice(b",\"data\":[");
+
+    Bytes::from(buf)
+}
+
+pub(crate) fn create_json_response_stream<S, T, E>(
+    mut state: JsonResponseState<Vec<T>>,
+    stream: S,
+) -> Response
+where
+    S: Stream<Item = Result<T, E>> + Send + 'static,
+    T: Serialize + Send + 'static,
+    E: Into<JderError>,
+{
+    // header map error
+    if state.header_map_error.is_some() {
+        return create_json_response_send(state);
+    }
+
+    // the JSON output is set by the config layer
+    // before the first chunk is sent
+    let config: Arc<OnceLock<JsonOutput>> = Arc::new(OnceLock::new());
+
+    let initial: JsonStreamState<_> = JsonStreamState {
+        phase: JsonStreamPhase::Start,
+        stream: Box::pin(stream),
+        pagination: state.pagination.take(),
+        config: config.clone(),
+        output: JsonOutput::default(),
+    };
+
+    let body = stream::unfold(initial, |mut state| async move {
+        let chunk: Bytes = match state.phase {
+            | JsonStreamPhase::Start => {
+                state.phase = JsonStreamPhase::First;
+                state.output = state.config.get().cloned().unwrap_or_default();
+
+                create_json_stream_start(
+                    state.pagination.as_ref(),
+                    &state.output,
+                )
+            },
+            | JsonStreamPhase::First | JsonStreamPhase::Rest => {
+                let err: BoxError = match state.stream.next().await {
+                    | Some(Ok(item)) => match state.output.data_to_bytes(&item)
+                    {
+                        | Ok(item) => {
+                            let chunk: Bytes = match state.phase {
+                                | JsonStreamPhase::Rest => {
+                                    let mut buf: Vec<u8> =
+                                        Vec::with_capacity(item.len() + 1);
+
+                                    buf.push(b',');
+                                    buf.extend_from_slice(&item);
+
+                                    Bytes::from(buf)
+                                },
+                                | _ => item,
+                            };
+
+                            state.phase = JsonStreamPhase::Rest;
+
+                            return Some((Ok(chunk), state));
+                        },
+                        | Err(err) => err.into(),
+                    },
+                    | Some(Err(err)) => {
+                        let err: JderError = err.into();
+
+                        err.into()
+                    },
+                    | None => {
+                        state.phase = JsonStreamPhase::End;
+
+                        return Some((
+                            Ok(Bytes::from_static(STREAM_END)),
+                            state,
+                        ));
+                    },
+                };
+
+                // abort the body instead of ending it normally
+                tracing::error!(error = %err, "JSON response stream failed");
+
+                state.phase = JsonStreamPhase::End;
+
+                return Some((Err(err), state));
+            },
+            | JsonStreamPhase::End => return None,
+        };
+
+        Some((Ok(chunk), state))
+    });
+
+    // set content type
+    let mut header_map: HeaderMap = state.header_map;
+
+    header_map
+        .entry(header::CONTENT_TYPE)
+        .or_insert(HeaderValue::from_static("application/json"));
+
+    // result
+    let mut res: Response = Response::new(Body::from_stream(body));
+
+    *res.status_mut() = state.status;
+    *res.version_mut() = state.version;
+    *res.headers_mut() = header_map;
+
+    JsonBody::Stream(config).attach(res)
+}
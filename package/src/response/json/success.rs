@@ -1,9 +1,33 @@
-use http::{Error as HTTPError, HeaderName, HeaderValue, StatusCode, Version};
+use http::{
+    Error as HTTPError, HeaderName, HeaderValue, StatusCode, Version, header,
+};
 use serde::Serialize;
 
-use crate::response::{
-    Response,
-    json::{JsonResponseState, create_json_response_send},
+#[cfg(feature = "cookie")]
+use crate::response::cookie::{Cookie, create_removal_cookie};
+
+#[cfg(feature = "conditional_get")]
+use headers::{HeaderMapExt as _, LastModified};
+
+#[cfg(feature = "conditional_get")]
+use crate::response::header::create_weak_etag;
+
+#[cfg(feature = "stream")]
+use futures_util::stream::Stream;
+
+#[cfg(feature = "stream")]
+use crate::response::json::stream::create_json_response_stream;
+
+use crate::{
+    error::JderError,
+    response::{
+        Response,
+        header::append_header,
+        json::{
+            JsonResponseState, PageInfo, create_json_response_send,
+            create_json_response_try_send,
+        },
+    },
 };
 
 /// Functions for creating a success response.
@@ -68,6 +92,10 @@ impl<D: Serialize> JsonSuccessResponseFunctions<D> {
 
     /// Set a header for the response.
     ///
+    /// If the key or value is invalid,
+    /// [`send`](Self::send) will send an internal server error instead,
+    /// and [`try_send`](Self::try_send) will return the error.
+    ///
     /// For validation on key value, see
     /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
     ///
@@ -103,24 +131,13 @@ impl<D: Serialize> JsonSuccessResponseFunctions<D> {
         HeaderValue: TryFrom<V>,
         <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
     {
-        let key: HeaderName = match <HeaderName as TryFrom<K>>::try_from(key) {
-            | Ok(k) => k,
-            | Err(_) => {
-                self.state.is_header_map_failed = true;
-                return self;
-            },
-        };
-
-        let value: HeaderValue =
-            match <HeaderValue as TryFrom<V>>::try_from(value) {
-                | Ok(v) => v,
-                | Err(_) => {
-                    self.state.is_header_map_failed = true;
-                    return self;
-                },
-            };
-
-        self.state.header_map.try_append(key, value).unwrap();
+        if let Err(err) = append_header(&mut self.state.header_map, key, value)
+        {
+            // keep the first error
+            if self.state.header_map_error.is_none() {
+                self.state.header_map_error = Some(err);
+            }
+        }
 
         self
     }
@@ -176,8 +193,138 @@ impl<D: Serialize> JsonSuccessResponseFunctions<D> {
         self
     }
 
+    /// Set a cookie for the response,
+    /// available with `cookie` feature.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    ///     cookie::Cookie,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless()
+    ///         .cookie(Cookie::build(("session", "value")).http_only(true))
+    ///         .send()
+    /// }
+    /// ```
+    #[cfg(feature = "cookie")]
+    pub fn cookie<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        self.header(header::SET_COOKIE, cookie.into().encoded().to_string())
+    }
+
+    /// Remove a cookie on the client,
+    /// available with `cookie` feature.
+    ///
+    /// The path of the cookie is set to `/` if it is not set,
+    /// the path and domain should match the cookie to be removed.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless()
+    ///         .remove_cookie("session")
+    ///         .send()
+    /// }
+    /// ```
+    #[cfg(feature = "cookie")]
+    pub fn remove_cookie<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        self.cookie(create_removal_cookie(cookie))
+    }
+
+    /// Set a weak `ETag` for the response from a version,
+    /// available with `conditional_get` feature.
+    ///
+    /// Used by the [`ConditionalGet`](crate::layers::ConditionalGet) layer
+    /// instead of generating a strong `ETag` from the body.
+    ///
+    /// If the version contains characters not allowed in an entity tag,
+    /// e.g. `"` or spaces, an internal server error will be sent instead.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless()
+    ///         .etag(42)
+    ///         .send()
+    /// }
+    /// ```
+    #[cfg(feature = "conditional_get")]
+    pub fn etag<V: std::fmt::Display>(
+        mut self,
+        version: V,
+    ) -> Self {
+        match create_weak_etag(version) {
+            | Ok(etag) => self.header(http::header::ETAG, etag),
+            | Err(err) => {
+                // keep the first error
+                if self.state.header_map_error.is_none() {
+                    self.state.header_map_error = Some(err);
+                }
+
+                self
+            },
+        }
+    }
+
+    /// Set the `Last-Modified` header for the response,
+    /// available with `conditional_get` feature.
+    ///
+    /// Used by the [`ConditionalGet`](crate::layers::ConditionalGet) layer
+    /// for `If-Modified-Since` requests.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::SystemTime;
+    ///
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless()
+    ///         .last_modified(SystemTime::UNIX_EPOCH)
+    ///         .send()
+    /// }
+    /// ```
+    #[cfg(feature = "conditional_get")]
+    pub fn last_modified(
+        mut self,
+        time: std::time::SystemTime,
+    ) -> Self {
+        self.state.header_map.typed_insert(LastModified::from(time));
+
+        self
+    }
+
     /// Send the response.
     ///
+    /// If any header is invalid, a JSON failure response
+    /// with status code `500` and error code `server` will be sent instead.
+    ///
     /// ## Example
     ///
     /// ```no_run
@@ -190,9 +337,40 @@ impl<D: Serialize> JsonSuccessResponseFunctions<D> {
     ///     CreateJsonResponse::dataless().send()
     /// }
     /// ```
-    pub fn send(self) -> Response {
+    pub fn send(self) -> Response
+    where
+        D: Send + 'static,
+    {
         create_json_response_send(self.state)
     }
+
+    /// Send the response,
+    /// or returns an error naming the header
+    /// if any header is invalid.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     response::{
+    ///         Response,
+    ///         json::CreateJsonResponse,
+    ///     },
+    /// };
+    ///
+    /// async fn route() -> Result<Response, JderError> {
+    ///     CreateJsonResponse::dataless()
+    ///         .header("x-custom", "value")
+    ///         .try_send()
+    /// }
+    /// ```
+    pub fn try_send(self) -> Result<Response, JderError>
+    where
+        D: Send + 'static,
+    {
+        create_json_response_try_send(self.state)
+    }
 }
 
 impl<D> JsonSuccessResponseFunctions<D> {
@@ -226,4 +404,111 @@ impl<D> JsonSuccessResponseFunctions<D> {
 
         self
     }
+
+    /// Set the pagination for the response.
+    ///
+    /// If the URI is set with [`with_uri`](PageInfo::with_uri),
+    /// RFC 8288 `Link` header will also be added to the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::Uri;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{CreateJsonResponse, PageInfo},
+    /// };
+    ///
+    /// async fn route(uri: Uri) -> Response {
+    ///     CreateJsonResponse::success::<Vec<String>>()
+    ///         .data(vec!["Name".to_string()])
+    ///         .paginate(PageInfo::new(1, 20).with_total(1).with_uri(uri))
+    ///         .send()
+    /// }
+    /// ```
+    pub fn paginate(
+        mut self,
+        info: PageInfo,
+    ) -> Self {
+        if let Some(link) = info.to_link_header() {
+            if let Err(err) =
+                append_header(&mut self.state.header_map, header::LINK, link)
+            {
+                // keep the first error
+                if self.state.header_map_error.is_none() {
+                    self.state.header_map_error = Some(err);
+                }
+            }
+        }
+
+        self.state.pagination = Some(info);
+
+        self
+    }
+}
+
+#[cfg(feature = "stream")]
+impl<T: Serialize> JsonSuccessResponseFunctions<Vec<T>> {
+    /// Send the response with data streamed from a [`Stream`],
+    /// available with `stream` feature.
+    ///
+    /// Each item is serialized into the `data` array when it is ready,
+    /// so the whole collection is never held in memory.
+    ///
+    /// The status code, headers and `success` set to `true`
+    /// are sent before the first item,
+    /// and the body ends with a trailing `error`:
+    ///
+    /// ```json
+    /// {"success":true,"data":[...],"error":null}
+    /// ```
+    ///
+    /// If the stream yields an error,
+    /// the error is logged with `tracing` and the body is aborted
+    /// instead of ending normally,
+    /// so clients never receive a complete body
+    /// with `success` set to `true` for a failed stream.
+    ///
+    /// If any header is invalid, a JSON failure response
+    /// with status code `500` and error code `server` will be sent instead.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures_util::stream;
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     response::{
+    ///         Response,
+    ///         json::CreateJsonResponse,
+    ///     },
+    /// };
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct User {
+    ///     id: u64,
+    /// }
+    ///
+    /// async fn route() -> Response {
+    ///     let users = stream::iter(
+    ///         (0..100_000).map(|id| Ok::<User, JderError>(User { id })),
+    ///     );
+    ///
+    ///     CreateJsonResponse::success::<Vec<User>>().stream(users)
+    /// }
+    /// ```
+    ///
+    /// [`Stream`]: futures_util::stream::Stream
+    pub fn stream<S, E>(
+        self,
+        stream: S,
+    ) -> Response
+    where
+        S: Stream<Item = Result<T, E>> + Send + 'static,
+        T: Send + 'static,
+        E: Into<JderError>,
+    {
+        create_json_response_stream(self.state, stream)
+    }
 }
@@ -1,10 +1,47 @@
+/// Cookie module,
+/// available with `cookie` feature.
+#[cfg(feature = "cookie")]
+pub mod cookie;
+/// File module,
+/// available with `file` feature.
+#[cfg(feature = "file")]
+pub mod file;
 pub mod header;
 pub mod json;
+#[cfg(feature = "stream")]
+mod stream;
+
+use std::marker::PhantomData;
 
 use axum_core::body::Body;
 use http::{
     Error as HTTPError, HeaderMap, HeaderName, HeaderValue,
-    Response as _Response, StatusCode, Version, response::Builder,
+    Response as _Response, StatusCode, Version,
+};
+
+use crate::{
+    error::JderError,
+    response::{
+        header::{append_header, create_location},
+        json::{CreateJsonResponse, JsonResponseError},
+    },
+};
+
+#[cfg(feature = "cookie")]
+use crate::response::cookie::{Cookie, create_removal_cookie};
+
+#[cfg(feature = "conditional_get")]
+use headers::{HeaderMapExt as _, LastModified};
+
+#[cfg(feature = "conditional_get")]
+use crate::response::header::create_weak_etag;
+
+#[cfg(feature = "stream")]
+use crate::response::stream::{create_reader_body, create_stream_body};
+
+#[cfg(feature = "file")]
+use crate::response::file::{
+    FileResponseFunctions, create_attachment_response, create_file_response,
 };
 
 /// Response for routes.
@@ -16,7 +53,8 @@ struct ResponseState<B> {
     status: StatusCode,
     version: Version,
     header_map: HeaderMap,
-    body: B,
+    header_map_error: Option<JsonResponseError>,
+    body: PhantomData<B>,
 }
 
 /// Functions for creating response.
@@ -83,6 +121,13 @@ where
 
     /// Set a header for the response.
     ///
+    /// If the key or value is invalid,
+    /// [`body`](Self::body) will send an internal server error instead,
+    /// and [`try_body`](Self::try_body) will return the error.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    ///
     /// ## Example
     ///
     /// ```no_run
@@ -112,15 +157,13 @@ where
         HeaderValue: TryFrom<V>,
         <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
     {
-        let key: HeaderName = <HeaderName as TryFrom<K>>::try_from(key)
-            .map_err(Into::into)
-            .unwrap();
-
-        let value: HeaderValue = <HeaderValue as TryFrom<V>>::try_from(value)
-            .map_err(Into::into)
-            .unwrap();
-
-        self.state.header_map.try_append(key, value).unwrap();
+        if let Err(err) = append_header(&mut self.state.header_map, key, value)
+        {
+            // keep the first error
+            if self.state.header_map_error.is_none() {
+                self.state.header_map_error = Some(err);
+            }
+        }
 
         self
     }
@@ -174,8 +217,141 @@ where
         self
     }
 
+    /// Set a cookie for the response,
+    /// available with `cookie` feature.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse,
+    ///     cookie::Cookie,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateResponse::success()
+    ///         .cookie(Cookie::build(("session", "value")).http_only(true))
+    ///         .body("active")
+    /// }
+    /// ```
+    #[cfg(feature = "cookie")]
+    pub fn cookie<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        self.header(
+            http::header::SET_COOKIE,
+            cookie.into().encoded().to_string(),
+        )
+    }
+
+    /// Remove a cookie on the client,
+    /// available with `cookie` feature.
+    ///
+    /// The path of the cookie is set to `/` if it is not set,
+    /// the path and domain should match the cookie to be removed.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateResponse::success()
+    ///         .remove_cookie("session")
+    ///         .body("active")
+    /// }
+    /// ```
+    #[cfg(feature = "cookie")]
+    pub fn remove_cookie<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        self.cookie(create_removal_cookie(cookie))
+    }
+
+    /// Set a weak `ETag` for the response from a version,
+    /// available with `conditional_get` feature.
+    ///
+    /// Used by the [`ConditionalGet`](crate::layers::ConditionalGet) layer
+    /// instead of generating a strong `ETag` from the body.
+    ///
+    /// If the version contains characters not allowed in an entity tag,
+    /// e.g. `"` or spaces, an internal server error will be sent instead.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateResponse::success()
+    ///         .etag(42)
+    ///         .body("active")
+    /// }
+    /// ```
+    #[cfg(feature = "conditional_get")]
+    pub fn etag<V: std::fmt::Display>(
+        mut self,
+        version: V,
+    ) -> Self {
+        match create_weak_etag(version) {
+            | Ok(etag) => self.header(http::header::ETAG, etag),
+            | Err(err) => {
+                // keep the first error
+                if self.state.header_map_error.is_none() {
+                    self.state.header_map_error = Some(err);
+                }
+
+                self
+            },
+        }
+    }
+
+    /// Set the `Last-Modified` header for the response,
+    /// available with `conditional_get` feature.
+    ///
+    /// Used by the [`ConditionalGet`](crate::layers::ConditionalGet) layer
+    /// for `If-Modified-Since` requests.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::SystemTime;
+    ///
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateResponse::success()
+    ///         .last_modified(SystemTime::UNIX_EPOCH)
+    ///         .body("active")
+    /// }
+    /// ```
+    #[cfg(feature = "conditional_get")]
+    pub fn last_modified(
+        mut self,
+        time: std::time::SystemTime,
+    ) -> Self {
+        self.state.header_map.typed_insert(LastModified::from(time));
+
+        self
+    }
+
     /// Set the body of the response.
     ///
+    /// If any header is invalid, a JSON failure response
+    /// with status code `500` and error code `server` will be sent instead.
+    ///
     /// ## Example
     ///
     /// ```no_run
@@ -190,22 +366,160 @@ where
     /// }
     /// ```
     pub fn body(
-        mut self,
+        self,
         body: B,
     ) -> Response {
-        self.state.body = body;
+        match self.try_body(body) {
+            | Ok(res) => res,
+            | Err(err) => CreateJsonResponse::failure()
+                .status(err.status)
+                .error(err.to_json_response_error())
+                .send(),
+        }
+    }
+
+    /// Set the body of the response,
+    /// or returns an error if any header is invalid.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     error::JderError,
+    ///     response::{
+    ///         Response,
+    ///         CreateResponse
+    ///     },
+    /// };
+    ///
+    /// async fn route() -> Result<Response, JderError> {
+    ///     CreateResponse::success()
+    ///         .header("x-custom", "value")
+    ///         .try_body("active")
+    /// }
+    /// ```
+    pub fn try_body(
+        self,
+        body: B,
+    ) -> Result<Response, JderError> {
+        self.create_response(Body::from(body))
+    }
+
+    /// Create the response with a body,
+    /// or returns an error if any header is invalid.
+    fn create_response(
+        self,
+        body: Body,
+    ) -> Result<Response, JderError> {
+        if let Some(err) = self.state.header_map_error {
+            return Err(JderError::from_json_response_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                err,
+            ));
+        }
 
-        let mut builder: Builder = Response::builder()
-            .status(self.state.status)
-            .version(self.state.version);
+        let mut res: Response = Response::new(body);
 
-        for (header, value) in self.state.header_map {
-            if let Some(header) = header {
-                builder = builder.header(header, value);
-            }
+        *res.status_mut() = self.state.status;
+        *res.version_mut() = self.state.version;
+        *res.headers_mut() = self.state.header_map;
+
+        Ok(res)
+    }
+}
+
+#[cfg(feature = "stream")]
+impl ResponseFunctions<Body> {
+    /// Set a stream of bytes as the body of the response,
+    /// available with `stream` feature.
+    ///
+    /// The response is sent in chunks.
+    /// If the stream returns an error after the response is sent,
+    /// the error is logged with `tracing` and the connection is aborted,
+    /// so the client can tell the body is incomplete.
+    ///
+    /// If any header is invalid, a JSON failure response
+    /// with status code `500` and error code `server` will be sent instead.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    ///
+    /// use axum::http::header;
+    /// use bytes::Bytes;
+    /// use futures_util::{StreamExt, stream};
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     let lines = stream::iter(["a,b\n", "c,d\n"])
+    ///         .map(|line| Ok::<Bytes, Infallible>(Bytes::from(line)));
+    ///
+    ///     CreateResponse::success()
+    ///         .header(header::CONTENT_TYPE, "text/csv")
+    ///         .stream(lines)
+    /// }
+    /// ```
+    pub fn stream<S, E>(
+        self,
+        stream: S,
+    ) -> Response
+    where
+        S: futures_util::Stream<Item = Result<bytes::Bytes, E>>
+            + Send
+            + 'static,
+        E: Into<axum_core::BoxError>,
+    {
+        match self.create_response(create_stream_body(stream)) {
+            | Ok(res) => res,
+            | Err(err) => CreateJsonResponse::failure()
+                .status(err.status)
+                .error(err.to_json_response_error())
+                .send(),
         }
+    }
 
-        builder.body(Body::from(self.state.body)).unwrap()
+    /// Set a reader as the body of the response,
+    /// available with `stream` feature.
+    ///
+    /// The reader is read in chunks,
+    /// and read errors are handled as in [`stream`](Self::stream).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::header;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse
+    /// };
+    /// use tokio::fs::File;
+    ///
+    /// async fn route() -> Response {
+    ///     let file: File = File::open("logs/access.log").await.unwrap();
+    ///
+    ///     CreateResponse::success()
+    ///         .header(header::CONTENT_TYPE, "text/plain")
+    ///         .reader(file)
+    /// }
+    /// ```
+    pub fn reader<R>(
+        self,
+        reader: R,
+    ) -> Response
+    where
+        R: tokio::io::AsyncRead + Send + 'static,
+    {
+        match self.create_response(create_reader_body(reader)) {
+            | Ok(res) => res,
+            | Err(err) => CreateJsonResponse::failure()
+                .status(err.status)
+                .error(err.to_json_response_error())
+                .send(),
+        }
     }
 }
 
@@ -235,7 +549,8 @@ impl CreateResponse {
                 status: StatusCode::OK,
                 version: Version::HTTP_11,
                 header_map: HeaderMap::new(),
-                body: B::default(),
+                header_map_error: None,
+                body: PhantomData,
             },
         }
     }
@@ -247,8 +562,171 @@ impl CreateResponse {
                 status: StatusCode::BAD_REQUEST,
                 version: Version::HTTP_11,
                 header_map: HeaderMap::new(),
-                body: B::default(),
+                header_map_error: None,
+                body: PhantomData,
             },
         }
     }
+
+    /// Create a redirect response with status code `307`,
+    /// keeping the method and body of the request.
+    ///
+    /// The location must be an absolute `http` / `https` URL
+    /// or an absolute path,
+    /// or an internal server error will be sent instead.
+    /// The location is not resolved against the request,
+    /// so relative references such as `users/1` or `?page=2`
+    /// must be resolved with
+    /// [`RequestBase::resolve`](crate::extract::extra::RequestBase::resolve)
+    /// before being passed.
+    ///
+    /// For a redirect with a JSON body, see
+    /// [`CreateJsonResponse::redirect_temporary`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateResponse::redirect_temporary("/v2/users").body(())
+    /// }
+    /// ```
+    pub fn redirect_temporary<B: Default>(
+        location: &str
+    ) -> ResponseFunctions<B> {
+        create_redirect(StatusCode::TEMPORARY_REDIRECT, location)
+    }
+
+    /// Create a redirect response with status code `308`,
+    /// keeping the method and body of the request.
+    ///
+    /// See [`redirect_temporary`](Self::redirect_temporary)
+    /// for the location.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateResponse::redirect_permanent("/v2/users").body(())
+    /// }
+    /// ```
+    pub fn redirect_permanent<B: Default>(
+        location: &str
+    ) -> ResponseFunctions<B> {
+        create_redirect(StatusCode::PERMANENT_REDIRECT, location)
+    }
+
+    /// Create a redirect response with status code `303`,
+    /// telling the client to `GET` the location,
+    /// e.g. after submitting a form.
+    ///
+    /// See [`redirect_temporary`](Self::redirect_temporary)
+    /// for the location.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateResponse::redirect_see_other("/users/1").body(())
+    /// }
+    /// ```
+    pub fn redirect_see_other<B: Default>(
+        location: &str
+    ) -> ResponseFunctions<B> {
+        create_redirect(StatusCode::SEE_OTHER, location)
+    }
+
+    /// Create a file response streamed from disk,
+    /// available with `file` feature.
+    ///
+    /// The `Content-Type` header is guessed from the file extension,
+    /// and the `Content-Disposition` header is set to `inline`
+    /// with the filename.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::HeaderMap;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse
+    /// };
+    ///
+    /// async fn route(headers: HeaderMap) -> Response {
+    ///     CreateResponse::file("assets/logo.png")
+    ///         .range(&headers)
+    ///         .send()
+    ///         .await
+    /// }
+    /// ```
+    #[cfg(feature = "file")]
+    pub fn file<P: AsRef<std::path::Path>>(path: P) -> FileResponseFunctions {
+        create_file_response(path)
+    }
+
+    /// Create a download response streamed from a reader,
+    /// available with `file` feature.
+    ///
+    /// The `Content-Type` header is guessed from the filename,
+    /// and the `Content-Disposition` header is set to `attachment`
+    /// with the filename.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     CreateResponse
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     let csv: &[u8] = b"id,name\n1,Alpheus\n";
+    ///
+    ///     CreateResponse::attachment("users.csv", csv).send().await
+    /// }
+    /// ```
+    #[cfg(feature = "file")]
+    pub fn attachment<S, R>(
+        filename: S,
+        reader: R,
+    ) -> FileResponseFunctions
+    where
+        S: Into<String>,
+        R: tokio::io::AsyncRead + Send + 'static,
+    {
+        create_attachment_response(filename, reader)
+    }
+}
+
+/// Create a redirect response with a status code and location.
+fn create_redirect<B: Default>(
+    status: StatusCode,
+    location: &str,
+) -> ResponseFunctions<B> {
+    let mut res: ResponseFunctions<B> = CreateResponse::success();
+
+    res.state.status = status;
+
+    match create_location(location) {
+        | Ok(value) => {
+            res.state.header_map.insert(http::header::LOCATION, value);
+        },
+        | Err(err) => res.state.header_map_error = Some(err),
+    }
+
+    res
 }
@@ -0,0 +1,49 @@
+use axum_core::{BoxError, body::Body};
+use bytes::{Bytes, BytesMut};
+use futures_util::stream::{self, Stream, StreamExt};
+use tokio::io::{AsyncRead, AsyncReadExt as _};
+
+/// Maximum size of a chunk read from a reader.
+const READER_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Create a body from a stream of bytes.
+///
+/// Errors are logged with `tracing` and passed to the body,
+/// so the connection is aborted instead of ending the body normally.
+pub(crate) fn create_stream_body<S, E>(stream: S) -> Body
+where
+    S: Stream<Item = Result<Bytes, E>> + Send + 'static,
+    E: Into<BoxError>,
+{
+    Body::from_stream(stream.map(|chunk| {
+        chunk.map_err(|err| {
+            let err: BoxError = err.into();
+
+            tracing::error!(error = %err, "response body stream failed");
+
+            err
+        })
+    }))
+}
+
+/// Create a body from a reader.
+///
+/// Errors are handled as in [`create_stream_body`].
+pub(crate) fn create_reader_body<R>(reader: R) -> Body
+where
+    R: AsyncRead + Send + 'static,
+{
+    let chunks = stream::unfold(Some(Box::pin(reader)), |reader| async move {
+        let mut reader = reader?;
+        let mut buf: BytesMut = BytesMut::with_capacity(READER_CHUNK_SIZE);
+
+        match reader.read_buf(&mut buf).await {
+            | Ok(0) => None,
+            | Ok(_) => Some((Ok(buf.freeze()), Some(reader))),
+            // stop reading after an error
+            | Err(err) => Some((Err(err), None)),
+        }
+    });
+
+    create_stream_body(chunks)
+}
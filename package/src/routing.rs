@@ -0,0 +1,137 @@
+use axum::Router;
+
+use crate::response::{
+    Response,
+    json::{CreateJsonResponse, JsonResponseErrorCode},
+};
+
+/// Handler that sends a failure response
+/// with status code `404` and error code `not_found`.
+///
+/// Used by [`jder_fallback`](RouterExt::jder_fallback).
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::routing::not_found_fallback;
+///
+/// let app: Router = Router::new().fallback(not_found_fallback);
+/// ```
+pub async fn not_found_fallback() -> Response {
+    CreateJsonResponse::failure()
+        .code(JsonResponseErrorCode::NotFound)
+        .error_message("Route not found.")
+        .send()
+}
+
+/// Handler that sends a failure response
+/// with status code `405` and error code `method_not_allowed`.
+///
+/// Used by
+/// [`jder_method_not_allowed_fallback`](RouterExt::jder_method_not_allowed_fallback).
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::routing::method_not_allowed_fallback;
+///
+/// let app: Router =
+///     Router::new().method_not_allowed_fallback(method_not_allowed_fallback);
+/// ```
+pub async fn method_not_allowed_fallback() -> Response {
+    CreateJsonResponse::failure()
+        .code(JsonResponseErrorCode::MethodNotAllowed)
+        .error_message("Method not allowed.")
+        .send()
+}
+
+/// Extension for [`Router`] to send JSON failure responses
+/// for unknown routes and methods.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, routing::get};
+/// use jder_axum::routing::RouterExt;
+///
+/// async fn route() {}
+///
+/// let app: Router = Router::new()
+///     .route("/users", get(route))
+///     .jder_fallbacks();
+/// ```
+pub trait RouterExt {
+    /// Send a failure response with status code `404`
+    /// and error code `not_found` for unknown routes.
+    ///
+    /// For nested routers,
+    /// it applies to unknown routes under the nested path.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::Router;
+    /// use jder_axum::routing::RouterExt;
+    ///
+    /// let app: Router = Router::new().jder_fallback();
+    /// ```
+    fn jder_fallback(self) -> Self;
+
+    /// Send a failure response with status code `405`
+    /// and error code `method_not_allowed` for unsupported methods,
+    /// with the `Allow` header of the supported methods.
+    ///
+    /// It only applies to the routes added before,
+    /// so it should be called after adding the routes.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::{Router, routing::get};
+    /// use jder_axum::routing::RouterExt;
+    ///
+    /// async fn route() {}
+    ///
+    /// let app: Router = Router::new()
+    ///     .route("/users", get(route))
+    ///     .jder_method_not_allowed_fallback();
+    /// ```
+    fn jder_method_not_allowed_fallback(self) -> Self;
+
+    /// Apply both [`jder_fallback`](Self::jder_fallback)
+    /// and [`jder_method_not_allowed_fallback`](Self::jder_method_not_allowed_fallback).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::{Router, routing::get};
+    /// use jder_axum::routing::RouterExt;
+    ///
+    /// async fn route() {}
+    ///
+    /// let app: Router = Router::new()
+    ///     .route("/users", get(route))
+    ///     .jder_fallbacks();
+    /// ```
+    fn jder_fallbacks(self) -> Self
+    where
+        Self: Sized,
+    {
+        self.jder_fallback().jder_method_not_allowed_fallback()
+    }
+}
+
+impl<S> RouterExt for Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    fn jder_fallback(self) -> Self {
+        self.fallback(not_found_fallback)
+    }
+
+    fn jder_method_not_allowed_fallback(self) -> Self {
+        self.method_not_allowed_fallback(method_not_allowed_fallback)
+    }
+}
@@ -1,3 +1,6 @@
+// existing tests compare with bool literals and spell out sizes like `1 * 1024`
+#![allow(clippy::bool_assert_comparison, clippy::identity_op)]
+
 mod router;
 
 use std::net::SocketAddr;
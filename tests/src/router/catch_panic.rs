@@ -0,0 +1,43 @@
+use std::sync::Mutex;
+
+use axum::{Router, routing::get};
+use jder_axum::layers::{CatchPanic, catch_panic::CaughtPanic};
+
+/// Last panic caught by the callback.
+pub static LAST_PANIC: Mutex<Option<CaughtPanic>> = Mutex::new(None);
+
+async fn route_panic() -> &'static str {
+    panic!("Something went wrong")
+}
+
+async fn route_panic_formatted() -> &'static str {
+    let id: u64 = 42;
+
+    panic!("User {} is broken", id)
+}
+
+async fn route_ok() -> &'static str {
+    "active"
+}
+
+fn create_routes() -> Router {
+    Router::new()
+        .route("/panic", get(route_panic))
+        .route("/panic_formatted", get(route_panic_formatted))
+        .route("/ok", get(route_ok))
+}
+
+pub fn router_catch_panic() -> Router {
+    Router::new()
+        .nest("/default", create_routes().layer(CatchPanic::new()))
+        .nest(
+            "/debug",
+            create_routes().layer(
+                CatchPanic::new().debug(true).capture_location().on_panic(
+                    |panic| {
+                        *LAST_PANIC.lock().unwrap() = Some(panic.clone());
+                    },
+                ),
+            ),
+        )
+}
@@ -0,0 +1,41 @@
+use std::time::{Duration, SystemTime};
+
+use axum::{Router, routing::get};
+use jder_axum::{
+    layers::ConditionalGet,
+    response::{CreateResponse, Response, json::CreateJsonResponse},
+};
+
+async fn route_list() -> Response {
+    CreateJsonResponse::success::<Vec<String>>()
+        .data(vec!["Name".to_string()])
+        .send()
+}
+
+async fn route_version() -> Response {
+    CreateJsonResponse::success::<String>()
+        .data("Name".to_string())
+        .etag("v1")
+        .send()
+}
+
+async fn route_invalid_version() -> Response {
+    CreateJsonResponse::dataless().etag("v 1").send()
+}
+
+async fn route_modified() -> Response {
+    CreateResponse::success()
+        .last_modified(
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000),
+        )
+        .body("active")
+}
+
+pub fn router_conditional_get() -> Router {
+    Router::new()
+        .route("/list", get(route_list).post(route_list))
+        .route("/version", get(route_version))
+        .route("/invalid_version", get(route_invalid_version))
+        .route("/modified", get(route_modified))
+        .layer(ConditionalGet::new())
+}
@@ -0,0 +1,63 @@
+use axum::{Router, routing::get};
+use jder_axum::{
+    error::JderError,
+    extract::extra::cookie::{
+        Cookie, CookieJar, Key, PrivateCookieJar, SignedCookieJar,
+    },
+    response::{CreateResponse, Response, json::CreateJsonResponse},
+};
+
+async fn route_builder() -> Response {
+    CreateResponse::success()
+        .cookie(Cookie::build(("theme", "dark")).path("/").http_only(true))
+        .remove_cookie("session")
+        .body("active")
+}
+
+async fn route_builder_json() -> Response {
+    CreateJsonResponse::dataless()
+        .cookie(Cookie::new("theme", "dark mode"))
+        .send()
+}
+
+async fn route_jar(jar: CookieJar) -> Result<(CookieJar, String), JderError> {
+    let theme: Cookie<'static> = jar.require("theme")?;
+
+    Ok((jar.add(Cookie::new("visited", "true")), theme.value().to_string()))
+}
+
+async fn route_signed_set(jar: SignedCookieJar) -> (SignedCookieJar, Response) {
+    (
+        jar.add(Cookie::new("session", "abc")),
+        CreateJsonResponse::dataless().send(),
+    )
+}
+
+async fn route_signed_get(jar: SignedCookieJar) -> Result<String, JderError> {
+    Ok(jar.require("session")?.value().to_string())
+}
+
+async fn route_private_set(
+    jar: PrivateCookieJar
+) -> (PrivateCookieJar, Response) {
+    (
+        jar.add(Cookie::new("session", "abc")),
+        CreateJsonResponse::dataless().send(),
+    )
+}
+
+async fn route_private_get(jar: PrivateCookieJar) -> Result<String, JderError> {
+    Ok(jar.require("session")?.value().to_string())
+}
+
+pub fn router_cookie() -> Router {
+    Router::new()
+        .route("/builder", get(route_builder))
+        .route("/builder/json", get(route_builder_json))
+        .route("/jar", get(route_jar))
+        .route("/signed/set", get(route_signed_set))
+        .route("/signed/get", get(route_signed_get))
+        .route("/private/set", get(route_private_set))
+        .route("/private/get", get(route_private_get))
+        .with_state(Key::generate())
+}
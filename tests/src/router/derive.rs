@@ -0,0 +1,59 @@
+use axum::{Router, http::StatusCode, routing::post};
+use jder_axum::{
+    error::JderError,
+    response::{Response, json::CreateJsonResponse},
+};
+
+#[derive(JderError)]
+pub enum RouteError {
+    #[jder(
+        status = 404,
+        code = "user_not_found",
+        message = "User {id} not found"
+    )]
+    NotFound {
+        id: u64,
+        name: String,
+    },
+    #[jder(status = 422, field = "email", message = "Invalid email: {0:?}")]
+    InvalidEmail(String),
+    RateLimited,
+}
+
+async fn route_not_found() -> Result<(), RouteError> {
+    Err(RouteError::NotFound { id: 1, name: "Name".to_string() })
+}
+
+async fn route_not_found_manual() -> Response {
+    CreateJsonResponse::failure()
+        .status(StatusCode::NOT_FOUND)
+        .error_code("user_not_found")
+        .error_message("User 1 not found")
+        .send()
+}
+
+async fn route_invalid_email() -> Result<(), RouteError> {
+    Err(RouteError::InvalidEmail("email".to_string()))
+}
+
+async fn route_invalid_email_manual() -> Response {
+    CreateJsonResponse::failure()
+        .status(StatusCode::UNPROCESSABLE_ENTITY)
+        .error_code("invalid_email")
+        .error_field("email")
+        .error_message("Invalid email: \"email\"")
+        .send()
+}
+
+async fn route_rate_limited() -> Result<(), JderError> {
+    Err(RouteError::RateLimited)?
+}
+
+pub fn router_derive() -> Router {
+    Router::new()
+        .route("/not_found", post(route_not_found))
+        .route("/not_found/manual", post(route_not_found_manual))
+        .route("/invalid_email", post(route_invalid_email))
+        .route("/invalid_email/manual", post(route_invalid_email_manual))
+        .route("/rate_limited", post(route_rate_limited))
+}
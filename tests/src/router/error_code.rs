@@ -0,0 +1,65 @@
+use axum::{Router, http::StatusCode, routing::post};
+use jder_axum::response::{
+    Response,
+    json::{CreateJsonResponse, ErrorCode, JsonResponseErrorCode},
+};
+
+pub enum RouteErrorCode {
+    NotFound,
+    InvalidEmail,
+}
+
+impl ErrorCode for RouteErrorCode {
+    fn code(&self) -> &str {
+        match self {
+            | Self::NotFound => "user_not_found",
+            | Self::InvalidEmail => "invalid_email",
+        }
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            | Self::NotFound => StatusCode::NOT_FOUND,
+            | Self::InvalidEmail => StatusCode::UNPROCESSABLE_ENTITY,
+        }
+    }
+
+    fn field(&self) -> Option<&str> {
+        match self {
+            | Self::NotFound => None,
+            | Self::InvalidEmail => Some("email"),
+        }
+    }
+}
+
+async fn route_not_found() -> Response {
+    CreateJsonResponse::failure()
+        .error_message("User not found")
+        .code(RouteErrorCode::NotFound)
+        .send()
+}
+
+async fn route_invalid_email() -> Response {
+    CreateJsonResponse::failure().code(RouteErrorCode::InvalidEmail).send()
+}
+
+async fn route_builtin() -> Response {
+    CreateJsonResponse::failure().code(JsonResponseErrorCode::TooLarge).send()
+}
+
+async fn route_shortcut_not_found() -> Response {
+    CreateJsonResponse::not_found().error_field("id").send()
+}
+
+async fn route_shortcut_conflict() -> Response {
+    CreateJsonResponse::conflict().error_message("Email already exists").send()
+}
+
+pub fn router_error_code() -> Router {
+    Router::new()
+        .route("/not_found", post(route_not_found))
+        .route("/invalid_email", post(route_invalid_email))
+        .route("/builtin", post(route_builtin))
+        .route("/shortcut/not_found", post(route_shortcut_not_found))
+        .route("/shortcut/conflict", post(route_shortcut_conflict))
+}
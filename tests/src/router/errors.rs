@@ -0,0 +1,58 @@
+use axum::{Router, routing::post};
+use jder_axum::response::{
+    Response,
+    json::{CreateJsonResponse, JsonResponseError, JsonResponseErrorCode},
+};
+
+async fn route_multiple() -> Response {
+    CreateJsonResponse::unprocessable_entity()
+        .push_error(JsonResponseError {
+            code: JsonResponseErrorCode::Parse.to_string(),
+            field: Some("title".to_string()),
+            message: Some("Invalid title".to_string()),
+        })
+        .push_error(JsonResponseError {
+            code: JsonResponseErrorCode::Parse.to_string(),
+            field: Some("email".to_string()),
+            message: Some("Invalid email".to_string()),
+        })
+        .send()
+}
+
+async fn route_first() -> Response {
+    CreateJsonResponse::failure()
+        .push_error(JsonResponseError {
+            code: JsonResponseErrorCode::Parse.to_string(),
+            field: Some("name".to_string()),
+            message: None,
+        })
+        .send()
+}
+
+async fn route_updated() -> Response {
+    CreateJsonResponse::failure()
+        .push_error(JsonResponseError {
+            code: JsonResponseErrorCode::Parse.to_string(),
+            field: Some("name".to_string()),
+            message: None,
+        })
+        .push_error(JsonResponseError {
+            code: JsonResponseErrorCode::Parse.to_string(),
+            field: Some("email".to_string()),
+            message: None,
+        })
+        .error_message("Name is required.")
+        .send()
+}
+
+async fn route_single() -> Response {
+    CreateJsonResponse::failure().error_field("title").send()
+}
+
+pub fn router_errors() -> Router {
+    Router::new()
+        .route("/multiple", post(route_multiple))
+        .route("/first", post(route_first))
+        .route("/updated", post(route_updated))
+        .route("/single", post(route_single))
+}
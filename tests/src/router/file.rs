@@ -0,0 +1,47 @@
+use axum::{Router, http::HeaderMap, routing::get};
+use jder_axum::response::{CreateResponse, Response, cookie::Cookie};
+
+const ASSETS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets");
+
+async fn route_file(headers: HeaderMap) -> Response {
+    CreateResponse::file(format!("{}/alphabet.txt", ASSETS_DIR))
+        .range(&headers)
+        .send()
+        .await
+}
+
+async fn route_file_digits(headers: HeaderMap) -> Response {
+    CreateResponse::file(format!("{}/digits.txt", ASSETS_DIR))
+        .range(&headers)
+        .send()
+        .await
+}
+
+async fn route_file_missing() -> Response {
+    CreateResponse::file(format!("{}/missing.txt", ASSETS_DIR)).send().await
+}
+
+async fn route_attachment() -> Response {
+    let csv: &[u8] = b"id,name\n1,Alpheus\n";
+
+    CreateResponse::attachment("用戶 list.csv", csv).send().await
+}
+
+async fn route_attachment_cookie() -> Response {
+    let csv: &[u8] = b"id,name\n1,Alpheus\n";
+
+    CreateResponse::attachment("list.csv", csv)
+        .cookie(Cookie::new("downloaded", "true"))
+        .remove_cookie("download_token")
+        .send()
+        .await
+}
+
+pub fn router_file() -> Router {
+    Router::new()
+        .route("/", get(route_file))
+        .route("/digits", get(route_file_digits))
+        .route("/missing", get(route_file_missing))
+        .route("/attachment", get(route_attachment))
+        .route("/attachment/cookie", get(route_attachment_cookie))
+}
@@ -0,0 +1,34 @@
+use axum::{
+    Router,
+    http::{HeaderName, header},
+    routing::post,
+};
+use jder_axum::{error::JderError, response::json::Jder};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct RouteJderResponseData {
+    pub id: usize,
+    pub name: String,
+}
+
+async fn route_ok() -> Jder<RouteJderResponseData> {
+    Jder::ok(RouteJderResponseData { id: 1, name: "Name".to_string() })
+}
+
+async fn route_created() -> ([(HeaderName, &'static str); 1], Jder<usize>) {
+    ([(header::LOCATION, "/users/1")], Jder::created(1))
+}
+
+async fn route_result() -> Result<Jder<usize>, JderError> {
+    let id: usize = serde_json::from_str("abc")?;
+
+    Ok(Jder::ok(id))
+}
+
+pub fn router_jder() -> Router {
+    Router::new()
+        .route("/ok", post(route_ok))
+        .route("/created", post(route_created))
+        .route("/result", post(route_result))
+}
@@ -0,0 +1,50 @@
+use axum::{Router, routing::post};
+use jder_axum::{
+    error::JderError, extract::Json, response::json::JsonResponseErrorCode,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct RouteJderErrorResponseData {
+    pub name: String,
+}
+
+async fn route_ok() -> Result<Json<RouteJderErrorResponseData>, JderError> {
+    let data: RouteJderErrorResponseData =
+        serde_json::from_str("{\"name\":\"Name\"}")?;
+
+    Ok(Json(data))
+}
+
+async fn route_serde_json()
+-> Result<Json<RouteJderErrorResponseData>, JderError> {
+    let data: RouteJderErrorResponseData = serde_json::from_str("{")?;
+
+    Ok(Json(data))
+}
+
+async fn route_io() -> Result<Json<String>, JderError> {
+    let data: String = std::fs::read_to_string("/jder_axum/not_found.txt")?;
+
+    Ok(Json(data))
+}
+
+async fn route_code() -> Result<Json<String>, JderError> {
+    Err(JsonResponseErrorCode::Conflict)?
+}
+
+async fn route_custom() -> Result<Json<String>, JderError> {
+    Err(JderError::new(JsonResponseErrorCode::NotFound)
+        .with_field("id")
+        .with_message("User not found")
+        .with_source(std::io::Error::other("source")))
+}
+
+pub fn router_jder_error() -> Router {
+    Router::new()
+        .route("/ok", post(route_ok))
+        .route("/serde_json", post(route_serde_json))
+        .route("/io", post(route_io))
+        .route("/code", post(route_code))
+        .route("/custom", post(route_custom))
+}
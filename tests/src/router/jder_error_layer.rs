@@ -0,0 +1,87 @@
+use axum::{
+    Router,
+    body::Bytes,
+    extract::DefaultBodyLimit,
+    http::{StatusCode, header},
+    response::IntoResponse,
+    routing::{get, post},
+};
+use jder_axum::{
+    error::JderError,
+    layers::JderErrorLayer,
+    response::Response,
+    response::json::{CreateJsonResponse, JsonResponseErrorCode},
+};
+
+async fn route_text() -> impl IntoResponse {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        [(header::RETRY_AFTER, "30")],
+        "Under maintenance",
+    )
+}
+
+async fn route_teapot() -> impl IntoResponse {
+    (StatusCode::IM_A_TEAPOT, "I'm a teapot")
+}
+
+async fn route_html() -> impl IntoResponse {
+    (
+        StatusCode::BAD_GATEWAY,
+        [(header::CONTENT_TYPE, "text/html; charset=utf-8")],
+        "<h1>Bad gateway</h1>",
+    )
+}
+
+async fn route_encoded() -> impl IntoResponse {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        [
+            (header::CONTENT_ENCODING, "gzip"),
+            (header::ETAG, "\"maintenance\""),
+            (header::LAST_MODIFIED, "Wed, 21 Oct 2015 07:28:00 GMT"),
+        ],
+        vec![0x1f, 0x8b, 0x08, 0x00],
+    )
+}
+
+async fn route_json() -> Response {
+    CreateJsonResponse::failure()
+        .code(JsonResponseErrorCode::Conflict)
+        .error_message("Already exists.")
+        .send()
+}
+
+async fn route_body(body: Bytes) -> String {
+    String::from_utf8_lossy(&body).to_string()
+}
+
+fn create_routes() -> Router {
+    Router::new()
+        .route("/text", get(route_text))
+        .route("/teapot", get(route_teapot))
+        .route("/html", get(route_html))
+        .route("/encoded", get(route_encoded))
+        .route("/json", get(route_json))
+        .route("/body", post(route_body).layer(DefaultBodyLimit::max(4)))
+}
+
+pub fn router_jder_error_layer() -> Router {
+    Router::new()
+        .nest("/default", create_routes().layer(JderErrorLayer::new()))
+        .nest(
+            "/custom",
+            create_routes().layer(
+                JderErrorLayer::new()
+                    .allow_status(StatusCode::IM_A_TEAPOT)
+                    .allow_content_type("text/html")
+                    .map(|status, _| {
+                        JderError::new(JsonResponseErrorCode::from_status(
+                            status,
+                        ))
+                        .with_status(status)
+                        .with_message("Something went wrong.")
+                    }),
+            ),
+        )
+}
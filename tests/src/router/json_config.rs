@@ -0,0 +1,74 @@
+use axum::{Router, routing::get};
+use futures_util::stream;
+use jder_axum::{
+    error::JderError,
+    extract::Json,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseConfig},
+    },
+};
+use serde::Serialize;
+use serde_json::ser::PrettyFormatter;
+
+#[derive(Serialize)]
+struct RouteJsonConfigData {
+    name: String,
+}
+
+async fn route_success() -> Response {
+    CreateJsonResponse::success::<RouteJsonConfigData>()
+        .data(RouteJsonConfigData { name: "Name".to_string() })
+        .send()
+}
+
+async fn route_failure() -> Response {
+    CreateJsonResponse::not_found().send()
+}
+
+async fn route_json() -> Json<RouteJsonConfigData> {
+    Json(RouteJsonConfigData { name: "Name".to_string() })
+}
+
+async fn route_large() -> Json<u128> {
+    Json(1 << 70)
+}
+
+async fn route_spawn() -> Response {
+    tokio::task::spawn_blocking(|| CreateJsonResponse::not_found().send())
+        .await
+        .unwrap()
+}
+
+async fn route_stream() -> Response {
+    let items = stream::iter(vec![Ok::<_, JderError>(RouteJsonConfigData {
+        name: "Name".to_string(),
+    })]);
+
+    CreateJsonResponse::success::<Vec<RouteJsonConfigData>>().stream(items)
+}
+
+pub fn router_json_config() -> Router {
+    let routes: Router = Router::new()
+        .route("/success", get(route_success))
+        .route("/failure", get(route_failure))
+        .route("/json", get(route_json))
+        .route("/large", get(route_large))
+        .route("/spawn", get(route_spawn))
+        .route("/stream", get(route_stream));
+
+    Router::new()
+        .nest(
+            "/default",
+            routes.clone().layer(
+                JsonResponseConfig::new().pretty_query(true).omit_null(true),
+            ),
+        )
+        .nest(
+            "/formatter",
+            routes.layer(
+                JsonResponseConfig::new()
+                    .formatter(PrettyFormatter::with_indent(b"\t")),
+            ),
+        )
+}
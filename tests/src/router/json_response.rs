@@ -0,0 +1,55 @@
+use axum::{Router, routing::post};
+use jder_axum::response::json::{
+    JsonResponse, JsonResponseError, JsonResponseErrorCode,
+};
+
+async fn route_success() -> JsonResponse<String> {
+    JsonResponse {
+        success: true,
+        data: Some("Name".to_string()),
+        error: None,
+        ..Default::default()
+    }
+}
+
+async fn route_failure() -> JsonResponse<String> {
+    JsonResponse {
+        success: false,
+        data: None,
+        error: Some(JsonResponseError {
+            code: JsonResponseErrorCode::NotFound.to_string(),
+            field: Some("id".to_string()),
+            message: None,
+        }),
+        ..Default::default()
+    }
+}
+
+async fn route_failure_unknown() -> JsonResponse {
+    JsonResponse {
+        success: false,
+        data: None,
+        error: Some(JsonResponseError {
+            code: "custom".to_string(),
+            field: None,
+            message: None,
+        }),
+        ..Default::default()
+    }
+}
+
+async fn route_error() -> JsonResponseError {
+    JsonResponseError {
+        code: JsonResponseErrorCode::TooManyRequests.to_string(),
+        field: None,
+        message: Some("Slow down".to_string()),
+    }
+}
+
+pub fn router_json_response() -> Router {
+    Router::new()
+        .route("/success", post(route_success))
+        .route("/failure", post(route_failure))
+        .route("/failure/unknown", post(route_failure_unknown))
+        .route("/error", post(route_error))
+}
@@ -1,15 +1,36 @@
+pub mod catch_panic;
+pub mod conditional_get;
 pub mod connect_info;
+pub mod cookie;
+pub mod derive;
+pub mod error_code;
+pub mod errors;
+pub mod file;
 pub mod form;
 pub mod host;
+pub mod jder;
+pub mod jder_error;
+pub mod jder_error_layer;
 pub mod json;
+pub mod json_config;
+pub mod json_response;
 pub mod matched_path;
 pub mod multipart;
+pub mod negotiate;
 pub mod nested_path;
+pub mod pagination;
 pub mod path;
+pub mod precondition;
+pub mod problem_details;
 pub mod query;
+pub mod redirect;
 pub mod request_body_limit;
 pub mod request_time_limit;
+pub mod response;
+pub mod routing;
+pub mod safe_integers;
 pub mod scheme;
+pub mod stream;
 pub mod typed_header;
 
 use std::net::SocketAddr;
@@ -20,9 +41,30 @@ use axum::{
     routing::{get, post},
 };
 use axum_test::TestServer;
+use catch_panic::router_catch_panic;
+use conditional_get::router_conditional_get;
+use cookie::router_cookie;
+use derive::router_derive;
+use error_code::router_error_code;
+use errors::router_errors;
+use file::router_file;
+use jder::router_jder;
 use jder_axum::response::{Response, json::CreateJsonResponse};
+use jder_error::router_jder_error;
+use jder_error_layer::router_jder_error_layer;
+use json_config::router_json_config;
+use json_response::router_json_response;
+use negotiate::router_negotiate;
+use pagination::router_pagination;
+use precondition::router_precondition;
+use problem_details::router_problem_details;
+use redirect::router_redirect;
 use request_body_limit::router_request_body_limit;
 use request_time_limit::router_request_time_limit;
+use response::router_response;
+use routing::router_routing;
+use safe_integers::router_safe_integers;
+use stream::router_stream;
 
 use crate::router::connect_info::route_connect_info;
 use crate::router::form::route_form;
@@ -47,26 +89,47 @@ pub async fn route_index() -> Response {
 pub fn create_router() -> IntoMakeServiceWithConnectInfo<Router, SocketAddr> {
     Router::new()
         .route("/", get(route_index))
+        .nest("/catch_panic", router_catch_panic())
+        .nest("/conditional_get", router_conditional_get())
         .route("/connect_info", post(route_connect_info))
+        .nest("/cookie", router_cookie())
+        .nest("/derive", router_derive())
+        .nest("/error_code", router_error_code())
+        .nest("/errors", router_errors())
+        .nest("/file", router_file())
         .route("/host", post(route_host))
         .route("/form", post(route_form))
+        .nest("/jder", router_jder())
+        .nest("/jder_error", router_jder_error())
+        .nest("/jder_error_layer", router_jder_error_layer())
         .route("/json", post(route_json))
         .route("/json/optional", post(route_json_optional))
+        .nest("/json_config", router_json_config())
+        .nest("/json_response", router_json_response())
         .route("/matched_path", post(route_matched_path))
         .route("/multipart", post(route_multipart))
         .route("/multipart/file", post(route_multipart_file))
+        .nest("/negotiate", router_negotiate())
         .route("/nested_path", post(route_nested_path))
         .nest(
             "/{id}",
             Router::new().route("/nested_path", post(route_nested_path)),
         )
+        .nest("/pagination", router_pagination())
         .route("/path/{id}/{name}", post(route_path))
+        .nest("/precondition", router_precondition())
+        .nest("/problem_details", router_problem_details())
         .route("/query", post(route_query))
+        .nest("/redirect", router_redirect())
+        .nest("/routing", router_routing())
+        .nest("/safe_integers", router_safe_integers())
         .route("/scheme", post(route_scheme))
+        .nest("/stream", router_stream())
         .route("/typed_header", post(route_typed_header))
         .route("/typed_header/optional", post(route_typed_header_optional))
         .nest("/request_body_limit", router_request_body_limit())
         .nest("/request_time_limit", router_request_time_limit())
+        .nest("/response", router_response())
         .layer(DefaultBodyLimit::disable())
         .into_make_service_with_connect_info::<SocketAddr>()
 }
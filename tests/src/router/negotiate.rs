@@ -0,0 +1,37 @@
+use axum::{Router, http::StatusCode, routing::get};
+use jder_axum::{
+    error::JderError,
+    extract::Negotiate,
+    response::json::{JsonResponseErrorCode, Negotiated},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NegotiateUser {
+    pub id: usize,
+    pub name: String,
+}
+
+async fn route_user(negotiate: Negotiate) -> Negotiated<NegotiateUser> {
+    negotiate
+        .ok(NegotiateUser { id: 1, name: "Name".to_string() })
+        .status(StatusCode::CREATED)
+}
+
+async fn route_error(negotiate: Negotiate) -> Negotiated {
+    negotiate.error(
+        JderError::new(JsonResponseErrorCode::NotFound)
+            .with_message("User not found."),
+    )
+}
+
+async fn route_media_type(negotiate: Negotiate) -> String {
+    negotiate.media_type().to_string()
+}
+
+pub fn router_negotiate() -> Router {
+    Router::new()
+        .route("/user", get(route_user))
+        .route("/error", get(route_error))
+        .route("/media_type", get(route_media_type))
+}
@@ -0,0 +1,73 @@
+use axum::{Router, extract::OriginalUri, routing::get};
+use jder_axum::{
+    error::JderError,
+    extract::Query,
+    response::{
+        Response,
+        json::{CreateJsonResponse, CursorCodec, PageInfo},
+    },
+};
+use serde::{Deserialize, Serialize};
+
+pub const CURSOR_KEY: &str = "secret";
+
+#[derive(Serialize, Deserialize)]
+pub struct RoutePaginationCursor {
+    pub last_id: u64,
+}
+
+#[derive(Deserialize)]
+pub struct RoutePaginationQuery {
+    pub page: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+async fn route_page(
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<RoutePaginationQuery>,
+) -> Response {
+    let page: u64 = query.page.unwrap_or(1);
+
+    CreateJsonResponse::success::<Vec<u64>>()
+        .data(vec![page])
+        .paginate(PageInfo::new(page, 10).with_total(25).with_uri(uri))
+        .send()
+}
+
+async fn route_cursor(
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<RoutePaginationQuery>,
+) -> Result<Response, JderError> {
+    let codec: CursorCodec = CursorCodec::new(CURSOR_KEY);
+
+    let last_id: u64 = match query.cursor {
+        | Some(cursor) => {
+            codec.decode::<RoutePaginationCursor>(&cursor)?.last_id
+        },
+        | None => 0,
+    };
+
+    let next: String =
+        codec.encode(&RoutePaginationCursor { last_id: last_id + 10 })?;
+
+    Ok(CreateJsonResponse::success::<u64>()
+        .data(last_id)
+        .paginate(PageInfo::cursor(10).with_next_cursor(next).with_uri(uri))
+        .send())
+}
+
+async fn route_cursor_encoded(OriginalUri(uri): OriginalUri) -> Response {
+    CreateJsonResponse::success::<u64>()
+        .data(0)
+        .paginate(
+            PageInfo::cursor(10).with_next_cursor("a+b/c=&d").with_uri(uri),
+        )
+        .send()
+}
+
+pub fn router_pagination() -> Router {
+    Router::new()
+        .route("/page", get(route_page))
+        .route("/cursor", get(route_cursor))
+        .route("/cursor/encoded", get(route_cursor_encoded))
+}
@@ -0,0 +1,30 @@
+use std::time::{Duration, SystemTime};
+
+use axum::{Router, routing::put};
+use headers::ETag;
+use jder_axum::{
+    error::JderError,
+    extract::extra::{IfMatch, IfUnmodifiedSince},
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_if_match(if_match: IfMatch) -> Result<Response, JderError> {
+    if_match.check(&"\"v2\"".parse::<ETag>().unwrap())?;
+
+    Ok(CreateJsonResponse::dataless().send())
+}
+
+async fn route_if_unmodified_since(
+    if_unmodified_since: IfUnmodifiedSince
+) -> Result<Response, JderError> {
+    if_unmodified_since
+        .check(SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000))?;
+
+    Ok(CreateJsonResponse::dataless().send())
+}
+
+pub fn router_precondition() -> Router {
+    Router::new()
+        .route("/if_match", put(route_if_match))
+        .route("/if_unmodified_since", put(route_if_unmodified_since))
+}
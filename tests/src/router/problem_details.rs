@@ -0,0 +1,89 @@
+use axum::{
+    Router,
+    http::{StatusCode, header},
+    response::IntoResponse,
+    routing::get,
+};
+use jder_axum::{
+    error::JderError,
+    layers::ProblemDetailsLayer,
+    response::Response,
+    response::json::{
+        CreateJsonResponse, JsonResponseError, JsonResponseErrorCode,
+        ProblemDetails,
+    },
+};
+
+async fn route_failure() -> Response {
+    CreateJsonResponse::failure()
+        .code(JsonResponseErrorCode::NotFound)
+        .error_field("id")
+        .error_message("User not found.")
+        .send()
+}
+
+async fn route_errors() -> Response {
+    CreateJsonResponse::failure()
+        .status(StatusCode::UNPROCESSABLE_ENTITY)
+        .push_error(JsonResponseError {
+            code: JsonResponseErrorCode::UnprocessableEntity.to_string(),
+            field: Some("name".to_string()),
+            message: Some("Name is required.".to_string()),
+        })
+        .push_error(JsonResponseError {
+            code: JsonResponseErrorCode::UnprocessableEntity.to_string(),
+            field: Some("age".to_string()),
+            message: Some("Age must be positive.".to_string()),
+        })
+        .send()
+}
+
+async fn route_retry() -> Response {
+    let mut res: Response = CreateJsonResponse::service_unavailable().send();
+
+    res.headers_mut().insert(header::RETRY_AFTER, "30".parse().unwrap());
+    res.headers_mut().insert(header::ETAG, "\"retry\"".parse().unwrap());
+
+    res
+}
+
+async fn route_encoded() -> Response {
+    let mut res: Response = CreateJsonResponse::not_found().send();
+
+    res.headers_mut().insert(header::CONTENT_ENCODING, "gzip".parse().unwrap());
+
+    res
+}
+
+async fn route_success() -> Response {
+    CreateJsonResponse::dataless().send()
+}
+
+async fn route_text() -> impl IntoResponse {
+    (StatusCode::BAD_GATEWAY, "Bad gateway")
+}
+
+async fn route_error() -> Result<Response, JderError> {
+    Err(JderError::new(JsonResponseErrorCode::Forbidden)
+        .with_message("Access denied."))
+}
+
+async fn route_problem() -> ProblemDetails {
+    ProblemDetails::new(StatusCode::FORBIDDEN)
+        .with_type("https://example.com/probs/out-of-credit")
+        .with_detail("Your current balance is 30, but that costs 50.")
+        .with_extension("balance", 30)
+}
+
+pub fn router_problem_details() -> Router {
+    Router::new()
+        .route("/failure", get(route_failure))
+        .route("/errors", get(route_errors))
+        .route("/retry", get(route_retry))
+        .route("/encoded", get(route_encoded))
+        .route("/success", get(route_success))
+        .route("/text", get(route_text))
+        .route("/error", get(route_error))
+        .route("/problem", get(route_problem))
+        .layer(ProblemDetailsLayer::new())
+}
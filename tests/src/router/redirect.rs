@@ -0,0 +1,72 @@
+use axum::{Router, routing::post};
+use jder_axum::{
+    error::JderError,
+    extract::{Query, extra::RequestBase},
+    response::{CreateResponse, Response, json::CreateJsonResponse},
+};
+use serde::Deserialize;
+
+async fn route_temporary() -> Response {
+    CreateResponse::redirect_temporary("/v2/users").body(())
+}
+
+async fn route_permanent() -> Response {
+    CreateResponse::redirect_permanent("https://example.com/v2").body(())
+}
+
+async fn route_see_other() -> Response {
+    CreateResponse::redirect_see_other("/users/1").body(())
+}
+
+async fn route_invalid() -> Response {
+    CreateResponse::redirect_see_other("javascript:alert(1)").body(())
+}
+
+async fn route_json() -> Response {
+    CreateJsonResponse::redirect_see_other("/users/1").send()
+}
+
+async fn route_json_invalid() -> Response {
+    CreateJsonResponse::redirect_temporary("users/1").send()
+}
+
+async fn route_resolve(base: RequestBase) -> Result<Response, JderError> {
+    let location: String = base.resolve("/users/1")?;
+
+    Ok(CreateJsonResponse::redirect_see_other(&location).send())
+}
+
+async fn route_resolve_invalid(
+    base: RequestBase
+) -> Result<Response, JderError> {
+    let location: String = base.resolve("javascript:alert(1)")?;
+
+    Ok(CreateResponse::redirect_see_other(&location).body(()))
+}
+
+#[derive(Deserialize)]
+pub struct RouteResolveQuery {
+    pub location: String,
+}
+
+async fn route_resolve_relative(
+    base: RequestBase,
+    Query(query): Query<RouteResolveQuery>,
+) -> Result<Response, JderError> {
+    let location: String = base.resolve(&query.location)?;
+
+    Ok(CreateResponse::redirect_see_other(&location).body(()))
+}
+
+pub fn router_redirect() -> Router {
+    Router::new()
+        .route("/temporary", post(route_temporary))
+        .route("/permanent", post(route_permanent))
+        .route("/see_other", post(route_see_other))
+        .route("/invalid", post(route_invalid))
+        .route("/json", post(route_json))
+        .route("/json/invalid", post(route_json_invalid))
+        .route("/resolve", post(route_resolve))
+        .route("/resolve/invalid", post(route_resolve_invalid))
+        .route("/resolve/relative/{id}", post(route_resolve_relative))
+}
@@ -0,0 +1,101 @@
+use std::io::{Error, ErrorKind};
+
+use axum::{Router, body::Bytes, http::header, routing::post};
+use futures_util::{StreamExt, stream};
+use jder_axum::{
+    error::JderError,
+    response::{CreateResponse, Response, json::CreateJsonResponse},
+};
+
+async fn route_ok() -> Response {
+    CreateResponse::success()
+        .header(header::CONTENT_TYPE, "text/plain")
+        .header(header::SET_COOKIE, "a=1")
+        .header(header::SET_COOKIE, "b=2")
+        .body("active")
+}
+
+async fn route_invalid_header() -> Response {
+    CreateResponse::success()
+        .header("x-custom", "invalid\nvalue")
+        .body("active")
+}
+
+async fn route_try_body() -> Result<Response, JderError> {
+    let res: Response = CreateResponse::success()
+        .header("invalid header", "value")
+        .try_body("active")?;
+
+    Ok(res)
+}
+
+async fn route_stream() -> Response {
+    let chunks = stream::iter(["a,b\n", "c,d\n"])
+        .map(|chunk| Ok::<Bytes, Error>(Bytes::from(chunk)));
+
+    CreateResponse::success()
+        .header(header::CONTENT_TYPE, "text/csv")
+        .stream(chunks)
+}
+
+async fn route_stream_error() -> Response {
+    let chunks = stream::iter([
+        Ok(Bytes::from("a,b\n")),
+        Err(Error::new(ErrorKind::ConnectionReset, "source lost")),
+    ]);
+
+    CreateResponse::success().stream(chunks)
+}
+
+async fn route_stream_invalid_header() -> Response {
+    CreateResponse::success()
+        .header("x-custom", "invalid\nvalue")
+        .stream(stream::empty::<Result<Bytes, Error>>())
+}
+
+async fn route_reader() -> Response {
+    let reader: &[u8] = b"active";
+
+    CreateResponse::success()
+        .header(header::CONTENT_TYPE, "text/plain")
+        .reader(reader)
+}
+
+async fn route_json_ok() -> Response {
+    CreateJsonResponse::dataless()
+        .header(header::SET_COOKIE, "a=1")
+        .header(header::SET_COOKIE, "b=2")
+        .send()
+}
+
+async fn route_json_invalid_header() -> Response {
+    CreateJsonResponse::dataless().header("x-custom", "invalid\nvalue").send()
+}
+
+async fn route_json_try_send() -> Response {
+    match CreateJsonResponse::failure()
+        .header("x-custom", "invalid\nvalue")
+        .try_send()
+    {
+        | Ok(res) => res,
+        | Err(err) => CreateResponse::failure().body(format!(
+            "{}: {}",
+            err.field.unwrap(),
+            err.status
+        )),
+    }
+}
+
+pub fn router_response() -> Router {
+    Router::new()
+        .route("/ok", post(route_ok))
+        .route("/invalid_header", post(route_invalid_header))
+        .route("/try_body", post(route_try_body))
+        .route("/stream", post(route_stream))
+        .route("/stream/error", post(route_stream_error))
+        .route("/stream/invalid_header", post(route_stream_invalid_header))
+        .route("/reader", post(route_reader))
+        .route("/json/ok", post(route_json_ok))
+        .route("/json/invalid_header", post(route_json_invalid_header))
+        .route("/json/try_send", post(route_json_try_send))
+}
@@ -0,0 +1,21 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+use jder_axum::routing::RouterExt;
+
+async fn route_ok() -> &'static str {
+    "active"
+}
+
+pub fn router_routing() -> Router {
+    let users: Router = Router::new()
+        .route("/", get(route_ok).post(route_ok))
+        .route("/{id}", get(route_ok))
+        .jder_fallbacks();
+
+    Router::new()
+        .route("/ok", post(route_ok))
+        .nest("/users", users)
+        .jder_fallbacks()
+}
@@ -0,0 +1,79 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+use jder_axum::{
+    extract::{
+        Json, Query,
+        query::{number_or_string, option_number_or_string},
+    },
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseConfig},
+    },
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct RouteSafeIntegersData {
+    #[serde(deserialize_with = "number_or_string")]
+    pub id: u64,
+    #[serde(deserialize_with = "number_or_string")]
+    pub offset: i64,
+    #[serde(deserialize_with = "number_or_string")]
+    pub count: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RouteSafeIntegersLargeData {
+    #[serde(deserialize_with = "number_or_string")]
+    pub id: u128,
+    #[serde(deserialize_with = "number_or_string")]
+    pub offset: i128,
+}
+
+#[derive(Deserialize)]
+pub struct RouteSafeIntegersQuery {
+    #[serde(default, deserialize_with = "option_number_or_string")]
+    pub after: Option<u64>,
+    #[serde(default, deserialize_with = "option_number_or_string")]
+    pub before: Option<u128>,
+}
+
+async fn route_send() -> Response {
+    CreateJsonResponse::success::<RouteSafeIntegersData>()
+        .data(RouteSafeIntegersData {
+            id: u64::MAX,
+            offset: i64::MIN,
+            count: 10,
+        })
+        .send()
+}
+
+async fn route_json(
+    Json(data): Json<RouteSafeIntegersData>
+) -> Json<RouteSafeIntegersData> {
+    Json(data)
+}
+
+async fn route_json_large(
+    Json(data): Json<RouteSafeIntegersLargeData>
+) -> Json<RouteSafeIntegersLargeData> {
+    Json(data)
+}
+
+async fn route_query(Query(query): Query<RouteSafeIntegersQuery>) -> String {
+    match query.before {
+        | Some(before) => format!("{:?}", Some(before)),
+        | None => format!("{:?}", query.after),
+    }
+}
+
+pub fn router_safe_integers() -> Router {
+    Router::new()
+        .route("/send", get(route_send))
+        .route("/json", post(route_json))
+        .route("/json/large", post(route_json_large))
+        .route("/query", get(route_query))
+        .layer(JsonResponseConfig::new().safe_integers(true))
+}
@@ -0,0 +1,60 @@
+use axum::{Router, routing::get};
+use futures_util::stream;
+use jder_axum::{
+    error::JderError,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseErrorCode, PageInfo},
+    },
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct RouteStreamData {
+    pub id: u64,
+}
+
+async fn route_ok() -> Response {
+    let items =
+        stream::iter((0..3).map(|id| {
+            Ok::<RouteStreamData, JderError>(RouteStreamData { id })
+        }));
+
+    CreateJsonResponse::success::<Vec<RouteStreamData>>()
+        .paginate(PageInfo::new(1, 3))
+        .stream(items)
+}
+
+async fn route_empty() -> Response {
+    let items = stream::iter(Vec::<Result<RouteStreamData, JderError>>::new());
+
+    CreateJsonResponse::success::<Vec<RouteStreamData>>().stream(items)
+}
+
+async fn route_error() -> Response {
+    let items = stream::iter((0..3).map(|id| {
+        match id {
+            | 2 => Err(JderError::new(JsonResponseErrorCode::Server)
+                .with_message("Database connection lost")),
+            | id => Ok(RouteStreamData { id }),
+        }
+    }));
+
+    CreateJsonResponse::success::<Vec<RouteStreamData>>().stream(items)
+}
+
+async fn route_invalid_header() -> Response {
+    let items = stream::iter(Vec::<Result<RouteStreamData, JderError>>::new());
+
+    CreateJsonResponse::success::<Vec<RouteStreamData>>()
+        .header("x-custom", "invalid\nvalue")
+        .stream(items)
+}
+
+pub fn router_stream() -> Router {
+    Router::new()
+        .route("/ok", get(route_ok))
+        .route("/empty", get(route_empty))
+        .route("/error", get(route_error))
+        .route("/invalid_header", get(route_invalid_header))
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::{
+        layers::catch_panic::CaughtPanic,
+        response::json::{
+            JsonResponse, JsonResponseError, JsonResponseErrorCode,
+        },
+    };
+
+    use crate::router::{catch_panic::LAST_PANIC, create_server};
+
+    #[tokio::test]
+    async fn test_ok() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/catch_panic/default/ok").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.text(), "active");
+    }
+
+    #[tokio::test]
+    async fn test_panic() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/catch_panic/default/panic").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::Server.as_str());
+        // message is hidden without debug
+        assert_eq!(err.message, None);
+    }
+
+    #[tokio::test]
+    async fn test_panic_debug() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/catch_panic/debug/panic_formatted").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.message, Some("User 42 is broken".to_string()));
+
+        let panic: CaughtPanic = LAST_PANIC.lock().unwrap().clone().unwrap();
+
+        assert_eq!(panic.message, Some("User 42 is broken".to_string()));
+        assert!(panic.location.unwrap().contains("router/catch_panic.rs"));
+    }
+}
@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{HeaderValue, Method, StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, JsonResponseError};
+
+    use crate::router::create_server;
+
+    const LAST_MODIFIED: &str = "Sun, 09 Sep 2001 01:46:40 GMT";
+
+    #[tokio::test]
+    async fn test_strong_etag() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/conditional_get/list").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let etag: HeaderValue = res.header(header::ETAG);
+
+        assert!(etag.to_str().unwrap().starts_with('"'));
+
+        let res: TestResponse = server
+            .get("/conditional_get/list")
+            .add_header(header::IF_NONE_MATCH, etag.clone())
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_MODIFIED);
+        assert_eq!(res.header(header::ETAG), etag);
+        assert_eq!(res.text(), "");
+        assert_eq!(res.maybe_header(header::CONTENT_TYPE), None);
+
+        let res: TestResponse = server
+            .get("/conditional_get/list")
+            .add_header(header::IF_NONE_MATCH, "\"other\"")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert!(res.json::<JsonResponse<Vec<String>>>().success);
+    }
+
+    #[tokio::test]
+    async fn test_head_etag() {
+        let server: TestServer = create_server();
+
+        let etag: HeaderValue =
+            server.get("/conditional_get/list").await.header(header::ETAG);
+
+        let res: TestResponse =
+            server.method(Method::HEAD, "/conditional_get/list").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(header::ETAG), etag);
+
+        let res: TestResponse = server
+            .method(Method::HEAD, "/conditional_get/list")
+            .add_header(header::IF_NONE_MATCH, etag)
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_MODIFIED);
+    }
+
+    #[tokio::test]
+    async fn test_weak_etag() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/conditional_get/version").await;
+
+        assert_eq!(res.header(header::ETAG), "W/\"v1\"");
+
+        // weak comparison
+        for tag in ["W/\"v1\"", "\"v1\"", "\"v0\", W/\"v1\"", "*"] {
+            let res: TestResponse = server
+                .get("/conditional_get/version")
+                .add_header(header::IF_NONE_MATCH, tag)
+                .await;
+
+            assert_eq!(res.status_code(), StatusCode::NOT_MODIFIED);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_version() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/conditional_get/invalid_version").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.field, Some("etag".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_last_modified() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/conditional_get/modified").await;
+
+        assert_eq!(res.header(header::LAST_MODIFIED), LAST_MODIFIED);
+
+        let res: TestResponse = server
+            .get("/conditional_get/modified")
+            .add_header(header::IF_MODIFIED_SINCE, LAST_MODIFIED)
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_MODIFIED);
+        assert_eq!(res.header(header::LAST_MODIFIED), LAST_MODIFIED);
+
+        let res: TestResponse = server
+            .get("/conditional_get/modified")
+            .add_header(
+                header::IF_MODIFIED_SINCE,
+                "Sat, 08 Sep 2001 01:46:40 GMT",
+            )
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.text(), "active");
+    }
+
+    #[tokio::test]
+    async fn test_post() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/conditional_get/list")
+            .add_header(header::IF_NONE_MATCH, "*")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.maybe_header(header::ETAG), None);
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{HeaderValue, StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        JsonResponse, JsonResponseError, JsonResponseErrorCode,
+    };
+
+    use crate::router::create_server;
+
+    fn get_set_cookies(res: &TestResponse) -> Vec<String> {
+        res.headers()
+            .get_all(header::SET_COOKIE)
+            .iter()
+            .map(|value: &HeaderValue| value.to_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_builder() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/cookie/builder").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let cookies: Vec<String> = get_set_cookies(&res);
+
+        assert_eq!(cookies.len(), 2);
+        assert_eq!(cookies[0], "theme=dark; HttpOnly; Path=/");
+        assert!(cookies[1].starts_with("session=; Path=/; Max-Age=0"));
+    }
+
+    #[tokio::test]
+    async fn test_builder_json() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/cookie/builder/json").await;
+
+        assert_eq!(get_set_cookies(&res), vec!["theme=dark%20mode"]);
+        assert!(res.json::<JsonResponse>().success);
+    }
+
+    #[tokio::test]
+    async fn test_jar() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/cookie/jar")
+            .add_header(header::COOKIE, "theme=dark")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.text(), "dark");
+        assert_eq!(get_set_cookies(&res), vec!["visited=true"]);
+    }
+
+    #[tokio::test]
+    async fn test_jar_missing() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/cookie/jar").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::BadRequest.as_str());
+        assert_eq!(err.field, Some("theme".to_string()));
+    }
+
+    async fn test_verified(path: &str) {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get(&format!("{}/set", path)).await;
+
+        let cookie: String = get_set_cookies(&res).remove(0);
+
+        assert_ne!(cookie, "session=abc");
+
+        // valid
+        let res: TestResponse = server
+            .get(&format!("{}/get", path))
+            .add_header(header::COOKIE, &cookie)
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.text(), "abc");
+
+        // tampered
+        let res: TestResponse = server
+            .get(&format!("{}/get", path))
+            .add_header(header::COOKIE, "session=tampered")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::Parse.as_str());
+        assert_eq!(err.field, Some("session".to_string()));
+
+        // missing
+        let res: TestResponse = server.get(&format!("{}/get", path)).await;
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::BadRequest.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_signed() {
+        test_verified("/cookie/signed").await;
+    }
+
+    #[tokio::test]
+    async fn test_private() {
+        test_verified("/cookie/private").await;
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, JsonResponseError};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/derive/not_found").await;
+        let manual: TestResponse =
+            server.post("/derive/not_found/manual").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+        assert_eq!(res.text(), manual.text());
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, "user_not_found");
+        assert_eq!(err.message, Some("User 1 not found".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_email() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/derive/invalid_email").await;
+        let manual: TestResponse =
+            server.post("/derive/invalid_email/manual").await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(res.text(), manual.text());
+    }
+
+    #[tokio::test]
+    async fn test_default() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/derive/rate_limited").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, "rate_limited");
+        assert_eq!(err.field, None);
+        assert_eq!(err.message, None);
+    }
+}
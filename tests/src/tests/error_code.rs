@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        JsonResponse, JsonResponseError, JsonResponseErrorCode,
+    };
+
+    use serde::Deserialize;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/error_code/not_found").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert!(!res.success);
+
+        let err: JsonResponseError = res.error.unwrap();
+
+        assert_eq!(err.code, "user_not_found");
+        assert_eq!(err.field, None);
+        assert_eq!(err.message, Some("User not found".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_email() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/error_code/invalid_email").await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, "invalid_email");
+        assert_eq!(err.field, Some("email".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_builtin() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/error_code/builtin").await;
+
+        assert_eq!(res.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::TooLarge.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_shortcut_not_found() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.post("/error_code/shortcut/not_found").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::NotFound.as_str());
+        assert_eq!(err.field, Some("id".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_shortcut_conflict() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.post("/error_code/shortcut/conflict").await;
+
+        assert_eq!(res.status_code(), StatusCode::CONFLICT);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::Conflict.as_str());
+        assert_eq!(err.message, Some("Email already exists".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_from_str() {
+        let code: JsonResponseErrorCode = "method_not_allowed".parse().unwrap();
+
+        assert_eq!(code, JsonResponseErrorCode::MethodNotAllowed);
+        assert_eq!(code.status(), StatusCode::METHOD_NOT_ALLOWED);
+
+        assert!("not_a_code".parse::<JsonResponseErrorCode>().is_err());
+    }
+
+    #[test]
+    fn test_from_str_all() {
+        for code in JsonResponseErrorCode::ALL {
+            assert_eq!(code.as_str().parse(), Ok(code));
+            assert_eq!(
+                serde_json::to_value(code).unwrap(),
+                serde_json::Value::from(code.as_str())
+            );
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct TypedJsonResponseError {
+        code: JsonResponseErrorCode,
+    }
+
+    #[derive(Deserialize)]
+    struct TypedJsonResponse {
+        error: Option<TypedJsonResponseError>,
+    }
+
+    #[tokio::test]
+    async fn test_deserialize() {
+        let server: TestServer = create_server();
+
+        let res: TypedJsonResponse = server
+            .post("/error_code/shortcut/conflict")
+            .await
+            .json::<TypedJsonResponse>();
+
+        assert_eq!(res.error.unwrap().code, JsonResponseErrorCode::Conflict);
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        JsonResponse, JsonResponseError, JsonResponseErrorCode,
+    };
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_multiple() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/errors/multiple").await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert!(!res.success);
+
+        let err: JsonResponseError = res.error.unwrap();
+
+        assert_eq!(
+            err.code,
+            JsonResponseErrorCode::UnprocessableEntity.as_str()
+        );
+        assert_eq!(err.field, None);
+
+        assert_eq!(res.errors.len(), 2);
+        assert_eq!(res.errors[0].field, Some("title".to_string()));
+        assert_eq!(res.errors[1].field, Some("email".to_string()));
+        assert_eq!(res.errors[1].message, Some("Invalid email".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_first() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse =
+            server.post("/errors/first").await.json::<JsonResponse>();
+
+        assert_eq!(res.error.unwrap().field, Some("name".to_string()));
+        assert_eq!(res.errors.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_updated() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse =
+            server.post("/errors/updated").await.json::<JsonResponse>();
+
+        let err: JsonResponseError = res.error.unwrap();
+
+        assert_eq!(err.field, Some("name".to_string()));
+        assert_eq!(err.message, Some("Name is required.".to_string()));
+        assert_eq!(res.errors.len(), 2);
+        assert_eq!(res.errors[0].field, Some("name".to_string()));
+        assert_eq!(res.errors[0].message, None);
+    }
+
+    #[tokio::test]
+    async fn test_single() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/errors/single").await;
+
+        assert!(!res.text().contains("errors"));
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.error.unwrap().field, Some("title".to_string()));
+        assert!(res.errors.is_empty());
+    }
+}
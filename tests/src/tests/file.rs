@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{HeaderValue, StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        JsonResponse, JsonResponseError, JsonResponseErrorCode,
+    };
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_file() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/file").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(header::CONTENT_TYPE), "text/plain");
+        assert_eq!(
+            res.header(header::CONTENT_DISPOSITION),
+            "inline; filename=\"alphabet.txt\""
+        );
+        assert_eq!(res.header(header::ACCEPT_RANGES), "bytes");
+        assert_eq!(res.header(header::CONTENT_LENGTH), "26");
+        assert!(res.maybe_header(header::LAST_MODIFIED).is_some());
+        assert_eq!(res.text(), "abcdefghijklmnopqrstuvwxyz");
+    }
+
+    #[tokio::test]
+    async fn test_file_range() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/file").add_header(header::RANGE, "bytes=2-4").await;
+
+        assert_eq!(res.status_code(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(res.header(header::CONTENT_RANGE), "bytes 2-4/26");
+        assert_eq!(res.header(header::CONTENT_LENGTH), "3");
+        assert_eq!(res.text(), "cde");
+
+        // suffix range
+        let res: TestResponse =
+            server.get("/file").add_header(header::RANGE, "bytes=-3").await;
+
+        assert_eq!(res.status_code(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(res.header(header::CONTENT_RANGE), "bytes 23-25/26");
+        assert_eq!(res.text(), "xyz");
+    }
+
+    #[tokio::test]
+    async fn test_file_multiple_ranges() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/file")
+            .add_header(header::RANGE, "bytes=0-1, 24-")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::PARTIAL_CONTENT);
+
+        let content_type: String =
+            res.header(header::CONTENT_TYPE).to_str().unwrap().to_string();
+        let boundary: &str = content_type
+            .strip_prefix("multipart/byteranges; boundary=")
+            .unwrap();
+
+        let expected: String = format!(
+            "\r\n--{b}\r\ncontent-type: text/plain\r\ncontent-range: bytes 0-1/26\r\n\r\nab\
+            \r\n--{b}\r\ncontent-type: text/plain\r\ncontent-range: bytes 24-25/26\r\n\r\nyz\
+            \r\n--{b}--\r\n",
+            b = boundary
+        );
+
+        assert_eq!(
+            res.header(header::CONTENT_LENGTH),
+            expected.len().to_string()
+        );
+        assert_eq!(res.text(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_file_coalesced_ranges() {
+        let server: TestServer = create_server();
+
+        // overlapping and adjacent ranges are sent as one range
+        let res: TestResponse = server
+            .get("/file")
+            .add_header(header::RANGE, "bytes=5-7, 0-2, 3-4, 1-3")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(res.header(header::CONTENT_RANGE), "bytes 0-7/26");
+        assert_eq!(res.text(), "abcdefgh");
+    }
+
+    #[tokio::test]
+    async fn test_file_too_many_ranges() {
+        let server: TestServer = create_server();
+
+        let ranges: Vec<String> =
+            (0..17).map(|i| format!("{}-{}", i * 2, i * 2)).collect();
+
+        // too many ranges are ignored
+        let res: TestResponse = server
+            .get("/file/digits")
+            .add_header(header::RANGE, format!("bytes={}", ranges.join(", ")))
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.maybe_header(header::CONTENT_RANGE), None);
+        assert_eq!(res.text().len(), 40);
+
+        let res: TestResponse = server
+            .get("/file/digits")
+            .add_header(
+                header::RANGE,
+                format!("bytes={}", ranges[..16].join(", ")),
+            )
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::PARTIAL_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_file_range_not_satisfiable() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/file").add_header(header::RANGE, "bytes=30-40").await;
+
+        assert_eq!(res.status_code(), StatusCode::RANGE_NOT_SATISFIABLE);
+        assert_eq!(res.header(header::CONTENT_RANGE), "bytes */26");
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(
+            err.code,
+            JsonResponseErrorCode::RangeNotSatisfiable.as_str()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_file_if_range() {
+        let server: TestServer = create_server();
+
+        // outdated `If-Range` sends the full file
+        let res: TestResponse = server
+            .get("/file")
+            .add_header(header::RANGE, "bytes=2-4")
+            .add_header(header::IF_RANGE, "Thu, 01 Jan 1970 00:00:00 GMT")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.text(), "abcdefghijklmnopqrstuvwxyz");
+    }
+
+    #[tokio::test]
+    async fn test_file_missing() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/file/missing").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::NotFound.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_attachment() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/file/attachment").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(header::CONTENT_TYPE), "text/csv");
+        assert_eq!(
+            res.header(header::CONTENT_DISPOSITION),
+            "attachment; filename=\"__ list.csv\"; filename*=UTF-8''%E7%94%A8%E6%88%B6%20list.csv"
+        );
+        assert_eq!(res.text(), "id,name\n1,Alpheus\n");
+    }
+
+    #[tokio::test]
+    async fn test_attachment_cookie() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/file/attachment/cookie").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let cookies: Vec<&HeaderValue> =
+            res.headers().get_all(header::SET_COOKIE).iter().collect();
+
+        assert_eq!(cookies.len(), 2);
+        assert_eq!(cookies[0], "downloaded=true");
+        assert!(cookies[1].to_str().unwrap().starts_with("download_token="));
+    }
+}
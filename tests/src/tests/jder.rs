@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, JsonResponseErrorCode};
+
+    use crate::router::create_server;
+    use crate::router::jder::RouteJderResponseData;
+
+    #[tokio::test]
+    async fn test_ok() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/jder/ok").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(
+            res.text(),
+            "{\"success\":true,\"data\":{\"id\":1,\"name\":\"Name\"},\"error\":null}"
+        );
+
+        let res: JsonResponse<RouteJderResponseData> =
+            res.json::<JsonResponse<RouteJderResponseData>>();
+
+        assert!(res.success);
+        assert_eq!(res.data.unwrap().name, "Name");
+    }
+
+    #[tokio::test]
+    async fn test_created() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/jder/created").await;
+
+        assert_eq!(res.status_code(), StatusCode::CREATED);
+        assert_eq!(res.header(header::LOCATION), "/users/1");
+
+        let res: JsonResponse<usize> = res.json::<JsonResponse<usize>>();
+
+        assert_eq!(res.data, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_result() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/jder/result").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(
+            res.error.unwrap().code,
+            JsonResponseErrorCode::Parse.as_str()
+        );
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod test {
+    use std::error::Error;
+
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::{
+        error::JderError,
+        response::json::{
+            JsonResponse, JsonResponseError, JsonResponseErrorCode,
+        },
+    };
+
+    use crate::router::create_server;
+    use crate::router::jder_error::RouteJderErrorResponseData;
+
+    #[tokio::test]
+    async fn test_ok() {
+        let server: TestServer = create_server();
+
+        let res: RouteJderErrorResponseData = server
+            .post("/jder_error/ok")
+            .await
+            .json::<RouteJderErrorResponseData>();
+
+        assert_eq!(res.name, "Name");
+    }
+
+    #[tokio::test]
+    async fn test_serde_json() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/jder_error/serde_json").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::Parse.as_str());
+        assert!(err.message.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_io() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/jder_error/io").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(!res.text().contains("not_found.txt"));
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::Server.as_str());
+        assert_eq!(err.message, Some("Internal server error.".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_code() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/jder_error/code").await;
+
+        assert_eq!(res.status_code(), StatusCode::CONFLICT);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::Conflict.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_custom() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/jder_error/custom").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+        assert!(!res.text().contains("source"));
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::NotFound.as_str());
+        assert_eq!(err.field, Some("id".to_string()));
+        assert_eq!(err.message, Some("User not found".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_source() {
+        let err: JderError = JderError::new(JsonResponseErrorCode::Server)
+            .with_source(std::io::Error::other("source"));
+
+        assert_eq!(err.to_string(), "server");
+        assert_eq!(err.source().unwrap().to_string(), "source");
+    }
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        JsonResponse, JsonResponseError, JsonResponseErrorCode,
+    };
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_text() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/jder_error_layer/default/text").await;
+
+        assert_eq!(res.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/json");
+        assert_eq!(res.header(header::RETRY_AFTER), "30");
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(
+            err.code,
+            JsonResponseErrorCode::ServiceUnavailable.as_str()
+        );
+        assert_eq!(err.message, Some("Under maintenance".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_status() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/jder_error_layer/default/teapot").await;
+
+        assert_eq!(res.status_code(), StatusCode::IM_A_TEAPOT);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::Unknown.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_encoded() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/jder_error_layer/default/encoded").await;
+
+        assert_eq!(res.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/json");
+        assert!(res.maybe_header(header::CONTENT_ENCODING).is_none());
+        assert!(res.maybe_header(header::ETAG).is_none());
+        assert!(res.maybe_header(header::LAST_MODIFIED).is_none());
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(
+            err.code,
+            JsonResponseErrorCode::ServiceUnavailable.as_str()
+        );
+        assert_eq!(err.message, None);
+    }
+
+    #[tokio::test]
+    async fn test_json_kept() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/jder_error_layer/default/json").await;
+
+        assert_eq!(res.status_code(), StatusCode::CONFLICT);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::Conflict.as_str());
+        assert_eq!(err.message, Some("Already exists.".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_method_not_allowed() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.post("/jder_error_layer/default/text").await;
+
+        assert_eq!(res.status_code(), StatusCode::METHOD_NOT_ALLOWED);
+        assert!(res.maybe_header(header::ALLOW).is_some());
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::MethodNotAllowed.as_str());
+        assert_eq!(err.message, None);
+    }
+
+    #[tokio::test]
+    async fn test_body_limit() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/jder_error_layer/default/body")
+            .text("too large")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::TooLarge.as_str());
+        assert!(err.message.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_allowlist() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/jder_error_layer/custom/teapot").await;
+
+        assert_eq!(res.status_code(), StatusCode::IM_A_TEAPOT);
+        assert_eq!(res.text(), "I'm a teapot");
+
+        let res: TestResponse =
+            server.get("/jder_error_layer/custom/html").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_GATEWAY);
+        assert_eq!(res.text(), "<h1>Bad gateway</h1>");
+    }
+
+    #[tokio::test]
+    async fn test_custom_map() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/jder_error_layer/custom/text").await;
+
+        assert_eq!(res.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.message, Some("Something went wrong.".to_string()));
+    }
+}
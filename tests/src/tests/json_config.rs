@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use serde_json::Value;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_compact() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/json_config/default/success").await;
+
+        assert_eq!(
+            res.text(),
+            "{\"success\":true,\"data\":{\"name\":\"Name\"},\"error\":null}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pretty_query() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/json_config/default/success?pretty").await;
+
+        assert_eq!(
+            res.text(),
+            "{\n  \"success\": true,\n  \"data\": {\n    \"name\": \"Name\"\n  },\n  \"error\": null\n}"
+        );
+
+        let res: TestResponse =
+            server.get("/json_config/default/json?pretty=true").await;
+
+        assert_eq!(res.text(), "{\n  \"name\": \"Name\"\n}");
+
+        let res: TestResponse =
+            server.get("/json_config/default/json?pretty=false").await;
+
+        assert_eq!(res.text(), "{\"name\":\"Name\"}");
+    }
+
+    #[tokio::test]
+    async fn test_pretty_query_large() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/json_config/default/large?pretty").await;
+
+        assert_eq!(res.text(), "1180591620717411303424");
+    }
+
+    #[tokio::test]
+    async fn test_pretty_query_disabled() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/json_config/formatter/json?pretty").await;
+
+        assert_eq!(res.text(), "{\n\t\"name\": \"Name\"\n}");
+    }
+
+    #[tokio::test]
+    async fn test_omit_null() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/json_config/default/failure").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+
+        let body: Value = res.json::<Value>();
+
+        assert_eq!(body["error"]["code"], "not_found");
+        assert_eq!(body["error"].get("field"), None);
+        assert_eq!(body["error"].get("message"), None);
+    }
+
+    #[tokio::test]
+    async fn test_formatter() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/json_config/formatter/failure").await;
+
+        let body: String = res.text();
+
+        // fields keep the order of the response
+        assert!(body.starts_with("{\n\t\"success\": false,\n\t\"data\": null"));
+        assert!(body.contains("\"field\": null"));
+    }
+
+    #[tokio::test]
+    async fn test_spawn() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/json_config/default/spawn?pretty").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+
+        let body: String = res.text();
+
+        assert!(body.starts_with("{\n  \"success\": false"));
+        assert!(!body.contains("\"field\""));
+    }
+
+    #[tokio::test]
+    async fn test_stream() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/json_config/default/stream").await;
+
+        assert_eq!(
+            res.text(),
+            "{\"success\":true,\"data\":[{\"name\":\"Name\"}],\"error\":null}"
+        );
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        JsonResponse, JsonResponseError, JsonResponseErrorCode,
+    };
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_success() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/json_response/success").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let res: JsonResponse<String> = res.json::<JsonResponse<String>>();
+
+        assert!(res.success);
+        assert_eq!(res.data, Some("Name".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_failure() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/json_response/failure").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::NotFound.as_str());
+        assert_eq!(err.field, Some("id".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_failure_unknown() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.post("/json_response/failure/unknown").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, "custom");
+    }
+
+    #[tokio::test]
+    async fn test_error() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/json_response/error").await;
+
+        assert_eq!(res.status_code(), StatusCode::TOO_MANY_REQUESTS);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert!(!res.success);
+        assert_eq!(res.error.unwrap().message, Some("Slow down".to_string()));
+    }
+}
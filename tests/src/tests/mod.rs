@@ -1,16 +1,37 @@
+pub mod catch_panic;
+pub mod conditional_get;
 pub mod connect_info;
+pub mod cookie;
+pub mod derive;
+pub mod error_code;
+pub mod errors;
+pub mod file;
 pub mod form;
 pub mod header;
 pub mod host;
+pub mod jder;
+pub mod jder_error;
+pub mod jder_error_layer;
 pub mod json;
+pub mod json_config;
+pub mod json_response;
 pub mod matched_path;
 pub mod multipart;
+pub mod negotiate;
 pub mod nested_path;
+pub mod pagination;
 pub mod path;
+pub mod precondition;
+pub mod problem_details;
 pub mod query;
+pub mod redirect;
 pub mod request_body_limit;
 pub mod request_time_limit;
+pub mod response;
+pub mod routing;
+pub mod safe_integers;
 pub mod scheme;
+pub mod stream;
 pub mod typed_header;
 
 #[cfg(test)]
@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        JsonResponse, JsonResponseError, JsonResponseErrorCode,
+    };
+
+    use crate::router::{create_server, negotiate::NegotiateUser};
+
+    fn expected_user() -> NegotiateUser {
+        NegotiateUser { id: 1, name: "Name".to_string() }
+    }
+
+    #[tokio::test]
+    async fn test_default_json() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/negotiate/user").await;
+
+        assert_eq!(res.status_code(), StatusCode::CREATED);
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/json");
+        assert_eq!(res.header(header::VARY), "accept");
+
+        let body: JsonResponse<NegotiateUser> =
+            res.json::<JsonResponse<NegotiateUser>>();
+
+        assert!(body.success);
+        assert_eq!(body.data, Some(expected_user()));
+    }
+
+    #[tokio::test]
+    async fn test_msgpack() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/negotiate/user")
+            .add_header(header::ACCEPT, "application/msgpack")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::CREATED);
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/msgpack");
+
+        let body: JsonResponse<NegotiateUser> =
+            rmp_serde::from_slice(res.as_bytes()).unwrap();
+
+        assert!(body.success);
+        assert_eq!(body.data, Some(expected_user()));
+    }
+
+    #[tokio::test]
+    async fn test_cbor() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/negotiate/user")
+            .add_header(header::ACCEPT, "application/cbor")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::CREATED);
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/cbor");
+
+        let body: JsonResponse<NegotiateUser> =
+            ciborium::from_reader(&res.as_bytes()[..]).unwrap();
+
+        assert!(body.success);
+        assert_eq!(body.data, Some(expected_user()));
+    }
+
+    #[tokio::test]
+    async fn test_quality() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/negotiate/media_type")
+            .add_header(
+                header::ACCEPT,
+                "application/json;q=0.5, application/cbor;q=0.8, */*;q=0.1",
+            )
+            .await;
+
+        assert_eq!(res.text(), "application/cbor");
+    }
+
+    #[tokio::test]
+    async fn test_alias() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/negotiate/media_type")
+            .add_header(header::ACCEPT, "application/x-msgpack")
+            .await;
+
+        assert_eq!(res.text(), "application/msgpack");
+    }
+
+    #[tokio::test]
+    async fn test_wildcard() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/negotiate/media_type")
+            .add_header(header::ACCEPT, "text/html, */*;q=0.8")
+            .await;
+
+        assert_eq!(res.text(), "application/json");
+    }
+
+    #[tokio::test]
+    async fn test_wildcard_excluded() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/negotiate/media_type")
+            .add_header(header::ACCEPT, "application/*, application/json;q=0")
+            .await;
+
+        assert_eq!(res.text(), "application/msgpack");
+    }
+
+    #[tokio::test]
+    async fn test_not_acceptable() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/negotiate/user")
+            .add_header(header::ACCEPT, "text/html")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_ACCEPTABLE);
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/json");
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::NotAcceptable.as_str());
+        assert_eq!(err.field, Some("accept".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_error() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/negotiate/error")
+            .add_header(header::ACCEPT, "application/cbor")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/cbor");
+
+        let body: JsonResponse =
+            ciborium::from_reader(&res.as_bytes()[..]).unwrap();
+
+        assert!(!body.success);
+
+        let err: JsonResponseError = body.error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::NotFound.as_str());
+        assert_eq!(err.message, Some("User not found.".to_string()));
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        CursorCodec, JsonResponse, JsonResponseErrorCode, PageInfo,
+    };
+
+    use crate::router::create_server;
+    use crate::router::pagination::{CURSOR_KEY, RoutePaginationCursor};
+
+    #[tokio::test]
+    async fn test_page() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/pagination/page?page=2&sort=name").await;
+
+        assert_eq!(
+            res.header(header::LINK),
+            "</pagination/page?sort=name&page=1&per_page=10>; rel=\"first\", \
+             </pagination/page?sort=name&page=1&per_page=10>; rel=\"prev\", \
+             </pagination/page?sort=name&page=3&per_page=10>; rel=\"next\", \
+             </pagination/page?sort=name&page=3&per_page=10>; rel=\"last\""
+        );
+
+        let res: JsonResponse<Vec<u64>> = res.json::<JsonResponse<Vec<u64>>>();
+
+        let info: PageInfo = res.pagination.unwrap();
+
+        assert_eq!(info.page, Some(2));
+        assert_eq!(info.per_page, 10);
+        assert_eq!(info.total, Some(25));
+    }
+
+    #[tokio::test]
+    async fn test_page_last() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/pagination/page?page=3").await;
+
+        assert!(!res.header(header::LINK).to_str().unwrap().contains("next"));
+    }
+
+    #[tokio::test]
+    async fn test_cursor() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<u64> =
+            server.get("/pagination/cursor").await.json::<JsonResponse<u64>>();
+
+        assert_eq!(res.data, Some(0));
+
+        let next: String = res.pagination.unwrap().next_cursor.unwrap();
+
+        let cursor: RoutePaginationCursor =
+            CursorCodec::new(CURSOR_KEY).decode(&next).unwrap();
+
+        assert_eq!(cursor.last_id, 10);
+
+        let res: JsonResponse<u64> = server
+            .get("/pagination/cursor")
+            .add_query_param("cursor", &next)
+            .await
+            .json::<JsonResponse<u64>>();
+
+        assert_eq!(res.data, Some(10));
+    }
+
+    #[tokio::test]
+    async fn test_cursor_encoded() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/pagination/cursor/encoded?sort=a%20b&cursor=old")
+            .await;
+
+        assert_eq!(
+            res.header(header::LINK),
+            "</pagination/cursor/encoded\
+             ?sort=a+b&cursor=a%2Bb%2Fc%3D%26d&per_page=10>; rel=\"next\""
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cursor_tampered() {
+        let server: TestServer = create_server();
+
+        let cursor: String = CursorCodec::new("other")
+            .encode(&RoutePaginationCursor { last_id: 100 })
+            .unwrap();
+
+        let res: TestResponse = server
+            .get("/pagination/cursor")
+            .add_query_param("cursor", cursor)
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+        let err = res.error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::Parse.as_str());
+        assert_eq!(err.field, Some("cursor".to_string()));
+    }
+}
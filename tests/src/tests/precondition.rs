@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        JsonResponse, JsonResponseError, JsonResponseErrorCode,
+    };
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_if_match() {
+        let server: TestServer = create_server();
+
+        for tag in ["\"v2\"", "\"v1\", \"v2\"", "*"] {
+            let res: TestResponse = server
+                .put("/precondition/if_match")
+                .add_header(header::IF_MATCH, tag)
+                .await;
+
+            assert_eq!(res.status_code(), StatusCode::OK);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_if_match_failed() {
+        let server: TestServer = create_server();
+
+        // weak tags never match with the strong comparison
+        for tag in ["\"v1\"", "W/\"v2\""] {
+            let res: TestResponse = server
+                .put("/precondition/if_match")
+                .add_header(header::IF_MATCH, tag)
+                .await;
+
+            assert_eq!(res.status_code(), StatusCode::PRECONDITION_FAILED);
+
+            let err: JsonResponseError =
+                res.json::<JsonResponse>().error.unwrap();
+
+            assert_eq!(
+                err.code,
+                JsonResponseErrorCode::PreconditionFailed.as_str()
+            );
+            assert_eq!(err.field, Some("if-match".to_string()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_if_match_missing() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.put("/precondition/if_match").await;
+
+        assert_eq!(res.status_code(), StatusCode::PRECONDITION_REQUIRED);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(
+            err.code,
+            JsonResponseErrorCode::PreconditionRequired.as_str()
+        );
+        assert_eq!(err.field, Some("if-match".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_if_unmodified_since_invalid() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .put("/precondition/if_unmodified_since")
+            .add_header(header::IF_UNMODIFIED_SINCE, "yesterday")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::Parse.as_str());
+        assert_eq!(err.field, Some("if-unmodified-since".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_if_unmodified_since() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .put("/precondition/if_unmodified_since")
+            .add_header(
+                header::IF_UNMODIFIED_SINCE,
+                "Sun, 09 Sep 2001 01:46:40 GMT",
+            )
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let res: TestResponse = server
+            .put("/precondition/if_unmodified_since")
+            .add_header(
+                header::IF_UNMODIFIED_SINCE,
+                "Sat, 08 Sep 2001 01:46:40 GMT",
+            )
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::PRECONDITION_FAILED);
+
+        let res: TestResponse =
+            server.put("/precondition/if_unmodified_since").await;
+
+        assert_eq!(res.status_code(), StatusCode::PRECONDITION_REQUIRED);
+    }
+}
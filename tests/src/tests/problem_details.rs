@@ -0,0 +1,233 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        JsonResponse, JsonResponseError, JsonResponseErrorCode, ProblemDetails,
+    };
+    use serde_json::{Value, json};
+
+    use crate::router::create_server;
+
+    const PROBLEM_JSON: &str = "application/problem+json";
+
+    #[tokio::test]
+    async fn test_failure() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/problem_details/failure")
+            .add_header(header::ACCEPT, PROBLEM_JSON)
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+        assert_eq!(res.header(header::CONTENT_TYPE), PROBLEM_JSON);
+        assert_eq!(res.header(header::VARY), "accept");
+
+        let problem: ProblemDetails = res.json::<ProblemDetails>();
+
+        assert_eq!(problem.r#type, "about:blank");
+        assert_eq!(problem.title, Some("Not Found".to_string()));
+        assert_eq!(problem.status, Some(404));
+        assert_eq!(problem.detail, Some("User not found.".to_string()));
+        assert_eq!(
+            problem.instance,
+            Some("/problem_details/failure".to_string())
+        );
+        assert_eq!(
+            problem.extensions.get("code"),
+            Some(&Value::from(JsonResponseErrorCode::NotFound.as_str()))
+        );
+        assert_eq!(problem.extensions.get("field"), Some(&Value::from("id")));
+    }
+
+    #[tokio::test]
+    async fn test_failure_default() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/problem_details/failure").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/json");
+        assert_eq!(res.header(header::VARY), "accept");
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::NotFound.as_str());
+        assert_eq!(err.message, Some("User not found.".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_failure_prefers_json() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/problem_details/failure")
+            .add_header(
+                header::ACCEPT,
+                "application/json, application/problem+json;q=0.5",
+            )
+            .await;
+
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/json");
+    }
+
+    #[tokio::test]
+    async fn test_failure_rejected() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/problem_details/failure")
+            .add_header(header::ACCEPT, "application/problem+json;q=0")
+            .await;
+
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/json");
+    }
+
+    #[tokio::test]
+    async fn test_errors() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/problem_details/errors")
+            .add_header(
+                header::ACCEPT,
+                "application/json;q=0.9, application/problem+json",
+            )
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(res.header(header::CONTENT_TYPE), PROBLEM_JSON);
+
+        let problem: Value = res.json::<Value>();
+
+        assert_eq!(problem["detail"], "Name is required.");
+        assert_eq!(problem["field"], "name");
+        assert_eq!(
+            problem["errors"],
+            json!([
+                {
+                    "code": "unprocessable_entity",
+                    "field": "name",
+                    "message": "Name is required.",
+                },
+                {
+                    "code": "unprocessable_entity",
+                    "field": "age",
+                    "message": "Age must be positive.",
+                },
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_headers_kept() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/problem_details/retry")
+            .add_header(header::ACCEPT, PROBLEM_JSON)
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(res.header(header::CONTENT_TYPE), PROBLEM_JSON);
+        assert_eq!(res.header(header::RETRY_AFTER), "30");
+        assert!(res.maybe_header(header::ETAG).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_encoded_kept() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/problem_details/encoded")
+            .add_header(header::ACCEPT, PROBLEM_JSON)
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/json");
+        assert_eq!(res.header(header::CONTENT_ENCODING), "gzip");
+    }
+
+    #[tokio::test]
+    async fn test_success_kept() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/problem_details/success")
+            .add_header(header::ACCEPT, PROBLEM_JSON)
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/json");
+        assert!(res.maybe_header(header::VARY).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_non_json_kept() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/problem_details/text")
+            .add_header(header::ACCEPT, PROBLEM_JSON)
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_GATEWAY);
+        assert_eq!(res.text(), "Bad gateway");
+    }
+
+    #[tokio::test]
+    async fn test_error() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/problem_details/error")
+            .add_header(header::ACCEPT, PROBLEM_JSON)
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::FORBIDDEN);
+
+        let problem: ProblemDetails = res.json::<ProblemDetails>();
+
+        assert_eq!(problem.title, Some("Forbidden".to_string()));
+        assert_eq!(problem.detail, Some("Access denied.".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_problem() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/problem_details/problem").await;
+
+        assert_eq!(res.status_code(), StatusCode::FORBIDDEN);
+        assert_eq!(res.header(header::CONTENT_TYPE), PROBLEM_JSON);
+
+        assert_eq!(
+            res.json::<Value>(),
+            json!({
+                "type": "https://example.com/probs/out-of-credit",
+                "title": "Forbidden",
+                "status": 403,
+                "detail": "Your current balance is 30, but that costs 50.",
+                "balance": 30,
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_json_response_error() {
+        let problem: ProblemDetails = ProblemDetails::from(JsonResponseError {
+            code: JsonResponseErrorCode::Conflict.to_string(),
+            field: None,
+            message: None,
+        });
+
+        assert_eq!(problem.status, Some(409));
+        assert_eq!(problem.detail, None);
+        assert_eq!(
+            problem.extensions.get("code"),
+            Some(&Value::from("conflict"))
+        );
+        assert!(problem.extensions.get("field").is_none());
+    }
+}
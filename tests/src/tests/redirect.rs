@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        JsonResponse, JsonResponseError, JsonResponseErrorCode, RedirectData,
+    };
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_redirect() {
+        let server: TestServer = create_server();
+
+        let cases: [(&str, StatusCode, &str); 3] = [
+            ("temporary", StatusCode::TEMPORARY_REDIRECT, "/v2/users"),
+            (
+                "permanent",
+                StatusCode::PERMANENT_REDIRECT,
+                "https://example.com/v2",
+            ),
+            ("see_other", StatusCode::SEE_OTHER, "/users/1"),
+        ];
+
+        for (path, status, location) in cases {
+            let res: TestResponse =
+                server.post(&format!("/redirect/{}", path)).await;
+
+            assert_eq!(res.status_code(), status);
+            assert_eq!(res.header(header::LOCATION), location);
+            assert_eq!(res.text(), "");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_redirect_invalid() {
+        let server: TestServer = create_server();
+
+        for path in ["/redirect/invalid", "/redirect/json/invalid"] {
+            let res: TestResponse = server.post(path).await;
+
+            assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+            assert_eq!(res.maybe_header(header::LOCATION), None);
+
+            let err: JsonResponseError =
+                res.json::<JsonResponse>().error.unwrap();
+
+            assert_eq!(err.code, JsonResponseErrorCode::Server.as_str());
+            assert_eq!(err.field, Some("location".to_string()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_redirect_json() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/redirect/json").await;
+
+        assert_eq!(res.status_code(), StatusCode::SEE_OTHER);
+        assert_eq!(res.header(header::LOCATION), "/users/1");
+
+        let res: JsonResponse<RedirectData> =
+            res.json::<JsonResponse<RedirectData>>();
+
+        assert!(res.success);
+        assert_eq!(res.data.unwrap().location, "/users/1");
+    }
+
+    #[tokio::test]
+    async fn test_redirect_resolve() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/redirect/resolve")
+            .add_header(header::HOST, "example.com")
+            .add_header("x-forwarded-proto", "https")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::SEE_OTHER);
+        assert_eq!(res.header(header::LOCATION), "https://example.com/users/1");
+
+        let res: JsonResponse<RedirectData> =
+            res.json::<JsonResponse<RedirectData>>();
+
+        assert_eq!(res.data.unwrap().location, "https://example.com/users/1");
+    }
+
+    #[tokio::test]
+    async fn test_redirect_resolve_invalid() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/redirect/resolve/invalid")
+            .add_header(header::HOST, "example.com")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::Parse.as_str());
+        assert_eq!(err.field, Some("location".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_redirect_resolve_relative() {
+        let server: TestServer = create_server();
+
+        let cases: [(&str, &str); 6] = [
+            ("2", "http://example.com/redirect/resolve/relative/2"),
+            ("./", "http://example.com/redirect/resolve/relative/"),
+            ("../users/1", "http://example.com/redirect/resolve/users/1"),
+            ("../../../..", "http://example.com/"),
+            ("/teams/./1", "http://example.com/teams/1"),
+            ("//example.org/users", "http://example.org/users"),
+        ];
+
+        for (location, expected) in cases {
+            let res: TestResponse = server
+                .post("/redirect/resolve/relative/1")
+                .add_query_param("location", location)
+                .add_header(header::HOST, "example.com")
+                .await;
+
+            assert_eq!(res.status_code(), StatusCode::SEE_OTHER);
+            assert_eq!(res.header(header::LOCATION), expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_redirect_resolve_query() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/redirect/resolve/relative/1?location=%3Fpage%3D2")
+            .add_header(header::HOST, "example.com")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::SEE_OTHER);
+        assert_eq!(
+            res.header(header::LOCATION),
+            "http://example.com/redirect/resolve/relative/1?page=2"
+        );
+    }
+}
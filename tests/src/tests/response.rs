@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod test {
+    use axum::{
+        Router,
+        body::{Body, to_bytes},
+        http::{HeaderValue, Method, Request, StatusCode, header},
+    };
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::{
+        Response,
+        json::{JsonResponse, JsonResponseError, JsonResponseErrorCode},
+    };
+    use tower::ServiceExt;
+
+    use crate::router::{create_server, response::router_response};
+
+    #[tokio::test]
+    async fn test_ok() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/response/ok").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.text(), "active");
+
+        let cookies: Vec<&HeaderValue> =
+            res.headers().get_all(header::SET_COOKIE).iter().collect();
+
+        assert_eq!(cookies.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_json_ok() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/response/json/ok").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/json");
+
+        let cookies: Vec<&HeaderValue> =
+            res.headers().get_all(header::SET_COOKIE).iter().collect();
+
+        assert_eq!(cookies.len(), 2);
+        assert!(res.json::<JsonResponse>().success);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_header() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/response/invalid_header").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::Server.as_str());
+        assert_eq!(err.field, Some("x-custom".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_try_body() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/response/try_body").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::Server.as_str());
+        assert_eq!(err.field, Some("header_map".to_string()));
+        assert!(err.message.unwrap().contains("`&str`"));
+    }
+
+    #[tokio::test]
+    async fn test_json_invalid_header() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.post("/response/json/invalid_header").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::Server.as_str());
+        assert_eq!(err.field, Some("x-custom".to_string()));
+        assert!(err.message.unwrap().contains("x-custom"));
+    }
+
+    #[tokio::test]
+    async fn test_json_try_send() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/response/json/try_send").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+        assert_eq!(res.text(), "x-custom: 500 Internal Server Error");
+    }
+
+    #[tokio::test]
+    async fn test_stream() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/response/stream").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(header::CONTENT_TYPE), "text/csv");
+        assert_eq!(res.maybe_header(header::CONTENT_LENGTH), None);
+        assert_eq!(res.text(), "a,b\nc,d\n");
+    }
+
+    #[tokio::test]
+    async fn test_stream_error() {
+        let router: Router = Router::new().nest("/response", router_response());
+
+        let req: Request<Body> = Request::builder()
+            .method(Method::POST)
+            .uri("/response/stream/error")
+            .body(Body::empty())
+            .unwrap();
+
+        let res: Response = router.oneshot(req).await.unwrap();
+
+        // status code is sent before the stream fails
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // body is aborted instead of ending normally
+        assert!(to_bytes(res.into_body(), usize::MAX).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_stream_invalid_header() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.post("/response/stream/invalid_header").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.field, Some("x-custom".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_reader() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/response/reader").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.text(), "active");
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        JsonResponse, JsonResponseError, JsonResponseErrorCode,
+    };
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_ok() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/routing/ok").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.text(), "active");
+    }
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let server: TestServer = create_server();
+
+        for path in ["/routing/unknown", "/routing/users/1/unknown"] {
+            let res: TestResponse = server.get(path).await;
+
+            assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+            assert_eq!(res.header(header::CONTENT_TYPE), "application/json");
+
+            let err: JsonResponseError =
+                res.json::<JsonResponse>().error.unwrap();
+
+            assert_eq!(err.code, JsonResponseErrorCode::NotFound.as_str());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_method_not_allowed() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/routing/ok").await;
+
+        assert_eq!(res.status_code(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(res.header(header::ALLOW), "POST");
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::MethodNotAllowed.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_method_not_allowed_nested() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.delete("/routing/users/1").await;
+
+        assert_eq!(res.status_code(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(res.header(header::ALLOW), "GET,HEAD");
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.code, JsonResponseErrorCode::MethodNotAllowed.as_str());
+    }
+}
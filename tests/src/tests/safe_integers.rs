@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use serde_json::{Value, json};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_send() {
+        let server: TestServer = create_server();
+
+        let res: Value =
+            server.get("/safe_integers/send").await.json::<Value>();
+
+        assert_eq!(res["data"]["id"], "18446744073709551615");
+        assert_eq!(res["data"]["offset"], "-9223372036854775808");
+        assert_eq!(res["data"]["count"], 10);
+    }
+
+    #[tokio::test]
+    async fn test_json() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/safe_integers/json")
+            .json(&json!({
+                "id": "9007199254740993",
+                "offset": -1,
+                "count": "5",
+            }))
+            .await;
+
+        assert_eq!(
+            res.text(),
+            "{\"id\":\"9007199254740993\",\"offset\":-1,\"count\":5}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_json_boundary() {
+        let server: TestServer = create_server();
+
+        let res: Value = server
+            .post("/safe_integers/json")
+            .json(&json!({
+                "id": 9007199254740991_u64,
+                "offset": -9007199254740992_i64,
+                "count": 0,
+            }))
+            .await
+            .json::<Value>();
+
+        assert_eq!(res["id"], 9007199254740991_u64);
+        assert_eq!(res["offset"], "-9007199254740992");
+    }
+
+    #[tokio::test]
+    async fn test_json_large() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/safe_integers/json/large")
+            .json(&json!({
+                "id": "1180591620717411303424",
+                "offset": "-1180591620717411303424",
+            }))
+            .await;
+
+        assert_eq!(
+            res.text(),
+            "{\"id\":\"1180591620717411303424\",\"offset\":\"-1180591620717411303424\"}"
+        );
+
+        let res: TestResponse = server
+            .post("/safe_integers/json/large")
+            .json(&json!({
+                "id": 5,
+                "offset": -5,
+            }))
+            .await;
+
+        assert_eq!(res.text(), "{\"id\":5,\"offset\":-5}");
+    }
+
+    #[tokio::test]
+    async fn test_json_invalid() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/safe_integers/json")
+            .json(&json!({
+                "id": "abc",
+                "offset": 0,
+                "count": 0,
+            }))
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_query() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/safe_integers/query?after=9007199254740993").await;
+
+        assert_eq!(res.text(), "Some(9007199254740993)");
+
+        let res: TestResponse = server.get("/safe_integers/query?after=").await;
+
+        assert_eq!(res.text(), "None");
+
+        let res: TestResponse = server.get("/safe_integers/query").await;
+
+        assert_eq!(res.text(), "None");
+
+        let res: TestResponse = server
+            .get("/safe_integers/query?before=1180591620717411303424")
+            .await;
+
+        assert_eq!(res.text(), "Some(1180591620717411303424)");
+    }
+}
@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod test {
+    use axum::{
+        Router,
+        body::{Body, to_bytes},
+        http::{Request, StatusCode, header},
+    };
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::{
+        Response,
+        json::{JsonResponse, JsonResponseError},
+    };
+    use tower::ServiceExt;
+
+    use crate::router::create_server;
+    use crate::router::stream::{RouteStreamData, router_stream};
+
+    #[tokio::test]
+    async fn test_ok() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/stream/ok").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/json");
+        assert!(res.text().starts_with("{\"success\":true,\"pagination\":"));
+
+        let res: JsonResponse<Vec<RouteStreamData>> =
+            res.json::<JsonResponse<Vec<RouteStreamData>>>();
+
+        assert!(res.success);
+        assert!(res.error.is_none());
+        assert_eq!(res.pagination.unwrap().page, Some(1));
+
+        let ids: Vec<u64> = res.data.unwrap().iter().map(|d| d.id).collect();
+
+        assert_eq!(ids, vec![0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_empty() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/stream/empty").await;
+
+        assert_eq!(res.text(), "{\"success\":true,\"data\":[],\"error\":null}");
+    }
+
+    #[tokio::test]
+    async fn test_error() {
+        let router: Router = Router::new().nest("/stream", router_stream());
+
+        let req: Request<Body> = Request::builder()
+            .uri("/stream/error")
+            .body(Body::empty())
+            .unwrap();
+
+        let res: Response = router.oneshot(req).await.unwrap();
+
+        // status code is sent before the stream fails
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // body is aborted instead of ending with `success` as `true`
+        assert!(to_bytes(res.into_body(), usize::MAX).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_header() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/stream/invalid_header").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let err: JsonResponseError = res.json::<JsonResponse>().error.unwrap();
+
+        assert_eq!(err.field, Some("x-custom".to_string()));
+    }
+}